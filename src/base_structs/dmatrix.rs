@@ -0,0 +1,557 @@
+use crate::base_structs::dvector::DVector;
+use crate::base_structs::matrix::Matrix;
+use crate::num_traits::scalar::Scalar;
+use std::fmt::Display;
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+//heap allocated matrix whose shape is only known at runtime
+#[derive(PartialEq, Debug, Clone)]
+pub struct DMatrix<T> {
+    pub(crate) data: Vec<Vec<T>>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for DMatrix<T> {
+    type Error = String;
+    fn try_from(d: Vec<Vec<T>>) -> Result<DMatrix<T>, String> {
+        let cols = d.first().map_or(0, |row| row.len());
+        for (j, row) in d.iter().enumerate() {
+            if row.len() != cols {
+                return Err(format!(
+                    "Expected row {} to have {} columns but it has {}",
+                    j,
+                    cols,
+                    row.len()
+                ));
+            }
+        }
+        Ok(DMatrix {
+            rows: d.len(),
+            cols,
+            data: d,
+        })
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<Matrix<T, M, N>> for DMatrix<T> {
+    fn from(m: Matrix<T, M, N>) -> DMatrix<T> {
+        DMatrix {
+            data: m.data,
+            rows: M,
+            cols: N,
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> TryFrom<DMatrix<T>> for Matrix<T, M, N> {
+    type Error = String;
+    fn try_from(m: DMatrix<T>) -> Result<Matrix<T, M, N>, String> {
+        Matrix::try_from(m.data)
+    }
+}
+
+impl<T> DMatrix<T> {
+    //builds a rows x cols matrix by calling f(row, col) for every element
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(rows: usize, cols: usize, mut f: F) -> DMatrix<T> {
+        let mut d = Vec::with_capacity(rows);
+        for j in 0..rows {
+            d.push((0..cols).map(|i| f(j, i)).collect());
+        }
+        DMatrix {
+            data: d,
+            rows,
+            cols,
+        }
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+}
+
+impl<T: Clone> DMatrix<T> {
+    //[a, b, c, d] with 2 columns becomes [[a, b], [c, d]]
+    pub fn from_row_slice(rows: usize, cols: usize, s: &[T]) -> Result<DMatrix<T>, String> {
+        if s.len() != rows * cols {
+            return Err(format!(
+                "Expected {} elements but got {}",
+                rows * cols,
+                s.len()
+            ));
+        }
+        Ok(DMatrix::from_fn(rows, cols, |j, i| s[j * cols + i].clone()))
+    }
+
+    pub fn as_vec(&self) -> Vec<Vec<T>> {
+        self.data.clone()
+    }
+
+    pub fn row(&self, j: usize) -> DVector<T> {
+        DVector::from(self.data[j].clone())
+    }
+}
+
+impl<T: Scalar> DMatrix<T> {
+    pub fn zeros(rows: usize, cols: usize) -> DMatrix<T> {
+        DMatrix::from_fn(rows, cols, |_, _| T::zero())
+    }
+
+    pub fn identity(n: usize) -> DMatrix<T> {
+        DMatrix::from_fn(n, n, |j, i| if i == j { T::one() } else { T::zero() })
+    }
+}
+
+impl<T> Index<(usize, usize)> for DMatrix<T> {
+    type Output = T;
+    fn index(&self, (j, i): (usize, usize)) -> &T {
+        if j >= self.rows || i >= self.cols {
+            panic!("DMatrix: index out of bound");
+        }
+        &self.data[j][i]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for DMatrix<T> {
+    fn index_mut(&mut self, (j, i): (usize, usize)) -> &mut T {
+        if j >= self.rows || i >= self.cols {
+            panic!("DMatrix: index out of bound");
+        }
+        &mut self.data[j][i]
+    }
+}
+
+impl<T: Scalar> DMatrix<T> {
+    fn check_shape(&self, v: &DMatrix<T>) {
+        if self.shape() != v.shape() {
+            panic!(
+                "DMatrix: shape mismatch, {:?} and {:?}",
+                self.shape(),
+                v.shape()
+            );
+        }
+    }
+
+    pub fn add(&mut self, v: &DMatrix<T>) {
+        self.check_shape(v);
+        for (row1, row2) in self.data.iter_mut().zip(v.data.iter()) {
+            for (item1, item2) in row1.iter_mut().zip(row2.iter()) {
+                *item1 = *item1 + *item2;
+            }
+        }
+    }
+
+    pub fn sub(&mut self, v: &DMatrix<T>) {
+        self.check_shape(v);
+        for (row1, row2) in self.data.iter_mut().zip(v.data.iter()) {
+            for (item1, item2) in row1.iter_mut().zip(row2.iter()) {
+                *item1 = *item1 - *item2;
+            }
+        }
+    }
+
+    pub fn scl(&mut self, a: T) {
+        for row in self.data.iter_mut() {
+            for item in row.iter_mut() {
+                *item = *item * a;
+            }
+        }
+    }
+
+    pub fn mul_vec(&self, rhs: &DVector<T>) -> DVector<T> {
+        if self.cols != rhs.len() {
+            panic!(
+                "DMatrix: cannot multiply {:?} matrix by vector of length {}",
+                self.shape(),
+                rhs.len()
+            );
+        }
+        DVector::from_fn(self.rows, |j| {
+            let mut sum = T::zero();
+            for (item1, item2) in self.data[j].iter().zip(rhs.as_slice()) {
+                sum = sum + *item1 * *item2;
+            }
+            sum
+        })
+    }
+
+    pub fn mul_mat(&self, rhs: &DMatrix<T>) -> DMatrix<T> {
+        if self.cols != rhs.rows {
+            panic!(
+                "DMatrix: cannot multiply {:?} matrix by {:?} matrix",
+                self.shape(),
+                rhs.shape()
+            );
+        }
+        let mut res = DMatrix::zeros(self.rows, rhs.cols);
+        for j in 0..self.rows {
+            for k in 0..self.cols {
+                let coef = self.data[j][k];
+                if coef == T::zero() {
+                    continue;
+                }
+                for (item, r) in res.data[j].iter_mut().zip(rhs.data[k].iter()) {
+                    *item = *item + coef * *r;
+                }
+            }
+        }
+        res
+    }
+
+    pub fn transpose(&self) -> DMatrix<T> {
+        DMatrix::from_fn(self.cols, self.rows, |j, i| self.data[i][j])
+    }
+
+    //values smaller than this are treated as zero during elimination
+    fn tolerance(&self) -> T {
+        let mut big = T::zero();
+        for row in self.data.iter() {
+            for item in row.iter() {
+                if item.abs() > big {
+                    big = item.abs();
+                }
+            }
+        }
+        big * T::from_usize(self.rows.max(self.cols)) * T::epsilon()
+    }
+
+    //index of the row at or below `from` with the largest absolute value in column `col`
+    fn pivot_row(data: &[Vec<T>], from: usize, col: usize) -> usize {
+        let mut big = from;
+        for (j, row) in data.iter().enumerate().skip(from + 1) {
+            if row[col].abs() > data[big][col].abs() {
+                big = j;
+            }
+        }
+        big
+    }
+
+    //gauss-jordan elimination with partial pivoting, returns the reduced matrix and its rank
+    fn reduce(&self) -> (DMatrix<T>, usize) {
+        let tol = self.tolerance();
+        let mut d = self.data.clone();
+        let mut lead = 0;
+        for col in 0..self.cols {
+            if lead == self.rows {
+                break;
+            }
+            let big = DMatrix::pivot_row(&d, lead, col);
+            if d[big][col].abs() <= tol {
+                for row in d.iter_mut().skip(lead) {
+                    row[col] = T::zero();
+                }
+                continue;
+            }
+            d.swap(lead, big);
+            let div = d[lead][col];
+            for item in d[lead].iter_mut() {
+                *item = *item / div;
+            }
+            let pivot = d[lead].clone();
+            for (j, row) in d.iter_mut().enumerate() {
+                let coef = row[col];
+                if j == lead || coef == T::zero() {
+                    continue;
+                }
+                for (item, p) in row.iter_mut().zip(pivot.iter()) {
+                    *item = *item - coef * *p;
+                }
+                row[col] = T::zero();
+            }
+            lead += 1;
+        }
+        (
+            DMatrix {
+                data: d,
+                rows: self.rows,
+                cols: self.cols,
+            },
+            lead,
+        )
+    }
+
+    //reduced row echelon form
+    pub fn row_echelon(&self) -> DMatrix<T> {
+        self.reduce().0
+    }
+
+    pub fn rank(&self) -> usize {
+        self.reduce().1
+    }
+
+    //O(n^3) elimination instead of the cofactor expansion used by Matrix
+    pub fn determinant(&self) -> T {
+        if !self.is_square() {
+            panic!("DMatrix: determinant requires a square matrix");
+        }
+        let tol = self.tolerance();
+        let mut d = self.data.clone();
+        let mut res = T::one();
+        for col in 0..self.cols {
+            let big = DMatrix::pivot_row(&d, col, col);
+            if d[big][col].abs() <= tol {
+                return T::zero();
+            }
+            if big != col {
+                d.swap(col, big);
+                res = -res;
+            }
+            let pivot = d[col].clone();
+            res = res * pivot[col];
+            for row in d.iter_mut().skip(col + 1) {
+                let k = row[col] / pivot[col];
+                for (item, p) in row.iter_mut().zip(pivot.iter()).skip(col) {
+                    *item = *item - k * *p;
+                }
+            }
+        }
+        res
+    }
+
+    pub fn inverse(&self) -> Result<DMatrix<T>, String> {
+        if !self.is_square() {
+            return Err(String::from("matrix is not square"));
+        }
+        let n = self.rows;
+        let tol = self.tolerance();
+        let mut d = self.data.clone();
+        let mut res = DMatrix::<T>::identity(n).data;
+        for col in 0..n {
+            let big = DMatrix::pivot_row(&d, col, col);
+            if d[big][col].abs() <= tol {
+                return Err(String::from("matrix is singular"));
+            }
+            d.swap(col, big);
+            res.swap(col, big);
+            let div = d[col][col];
+            for i in 0..n {
+                d[col][i] = d[col][i] / div;
+                res[col][i] = res[col][i] / div;
+            }
+            for j in 0..n {
+                let k = d[j][col];
+                if j == col || k == T::zero() {
+                    continue;
+                }
+                for i in 0..n {
+                    d[j][i] = d[j][i] - k * d[col][i];
+                    res[j][i] = res[j][i] - k * res[col][i];
+                }
+            }
+        }
+        Ok(DMatrix {
+            data: res,
+            rows: n,
+            cols: n,
+        })
+    }
+}
+
+impl<T: Scalar> Add<DMatrix<T>> for DMatrix<T> {
+    type Output = DMatrix<T>;
+    fn add(mut self, rhs: DMatrix<T>) -> DMatrix<T> {
+        DMatrix::add(&mut self, &rhs);
+        self
+    }
+}
+
+impl<T: Scalar> Sub<DMatrix<T>> for DMatrix<T> {
+    type Output = DMatrix<T>;
+    fn sub(mut self, rhs: DMatrix<T>) -> DMatrix<T> {
+        DMatrix::sub(&mut self, &rhs);
+        self
+    }
+}
+
+impl<T: Scalar> Mul<DMatrix<T>> for DMatrix<T> {
+    type Output = DMatrix<T>;
+    fn mul(self, rhs: DMatrix<T>) -> DMatrix<T> {
+        self.mul_mat(&rhs)
+    }
+}
+
+impl<T: Scalar> Mul<DVector<T>> for DMatrix<T> {
+    type Output = DVector<T>;
+    fn mul(self, rhs: DVector<T>) -> DVector<T> {
+        self.mul_vec(&rhs)
+    }
+}
+
+impl<T: Scalar> Mul<T> for DMatrix<T> {
+    type Output = DMatrix<T>;
+    fn mul(mut self, rhs: T) -> DMatrix<T> {
+        self.scl(rhs);
+        self
+    }
+}
+
+impl<T: Display> Display for DMatrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (j, row) in self.data.iter().enumerate() {
+            if j != 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[")?;
+            for (i, item) in row.iter().enumerate() {
+                if i != 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", item)?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod dynamic_matrix {
+    use super::DMatrix;
+    use crate::base_structs::dvector::DVector;
+    use crate::base_structs::matrix::Matrix;
+    use crate::utils::comp::dmatrices_are_equal;
+
+    fn dm(d: Vec<Vec<f32>>) -> DMatrix<f32> {
+        DMatrix::try_from(d).unwrap()
+    }
+
+    #[test]
+    fn test_try_from_ragged() {
+        let m = DMatrix::try_from(vec![vec![1., 2.], vec![3.]]);
+        assert_eq!(
+            m,
+            Err(String::from(
+                "Expected row 1 to have 2 columns but it has 1"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut u = dm(vec![vec![1., 2.], vec![3., 4.]]);
+        let v = dm(vec![vec![7., 4.], vec![-2., 2.]]);
+        u.add(&v);
+        assert_eq!(u, dm(vec![vec![8., 6.], vec![1., 6.]]));
+        u.sub(&v);
+        u.scl(2.);
+        assert_eq!(u, dm(vec![vec![2., 4.], vec![6., 8.]]));
+    }
+
+    #[test]
+    fn test_mul() {
+        let u = dm(vec![vec![3., -5.], vec![6., 8.]]);
+        let v = dm(vec![vec![2., 1.], vec![4., 2.]]);
+        assert_eq!(u.mul_mat(&v), dm(vec![vec![-14., -7.], vec![44., 22.]]));
+        let w = dm(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        let x = DVector::from(vec![1., 0., -1.]);
+        assert_eq!(w * x, DVector::from(vec![-2., -2.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "DMatrix: cannot multiply (2, 3) matrix by (2, 3) matrix")]
+    fn test_mul_mismatch() {
+        let w = dm(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        w.mul_mat(&w);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let u = dm(vec![vec![1., 2., 3.], vec![4., 5., 6.]]);
+        assert_eq!(
+            u.transpose(),
+            dm(vec![vec![1., 4.], vec![2., 5.], vec![3., 6.]])
+        );
+    }
+
+    #[test]
+    fn test_determinant() {
+        let u = dm(vec![vec![6., 1., 1.], vec![4., -2., 5.], vec![2., 8., 7.]]);
+        assert!((u.determinant() - -306.).abs() < 1e-3);
+        let u = dm(vec![
+            vec![1., 0., 2., -1.],
+            vec![3., 0., 0., 5.],
+            vec![2., 1., 4., -3.],
+            vec![1., 0., 5., 0.],
+        ]);
+        assert!((u.determinant() - 30.).abs() < 1e-4);
+        let u = dm(vec![vec![1., 2.], vec![2., 4.]]);
+        assert_eq!(u.determinant(), 0.);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let u = dm(vec![vec![3., 0., 2.], vec![2., 0., -2.], vec![0., 1., 1.]]);
+        let expected = dm(vec![
+            vec![0.2, 0.2, 0.0],
+            vec![-0.2, 0.3, 1.0],
+            vec![0.2, -0.3, 0.0],
+        ]);
+        assert!(dmatrices_are_equal(&u.inverse().unwrap(), &expected));
+        let u = dm(vec![vec![1., 2.], vec![2., 4.]]);
+        assert_eq!(u.inverse(), Err(String::from("matrix is singular")));
+        let u = dm(vec![vec![1., 2.]]);
+        assert_eq!(u.inverse(), Err(String::from("matrix is not square")));
+    }
+
+    #[test]
+    fn test_row_echelon_and_rank() {
+        let u = dm(vec![
+            vec![8.0, 5.0, -2.0, 4.0, 28.0],
+            vec![4.0, 2.5, 20.0, 4.0, -4.0],
+            vec![8.0, 5.0, 1.0, 4.0, 17.0],
+        ]);
+        let expected = dm(vec![
+            vec![1.0, 0.625, 0.0, 0.0, -73.0 / 6.0],
+            vec![0.0, 0.0, 1.0, 0.0, -11.0 / 3.0],
+            vec![0.0, 0.0, 0.0, 1.0, 29.5],
+        ]);
+        let res = u.row_echelon();
+        assert!(dmatrices_are_equal(&res, &expected), "{:?}", res);
+        assert_eq!(u.rank(), 3);
+        let u = dm(vec![vec![1., 2., 3.], vec![4., 5., 6.], vec![7., 8., 9.]]);
+        assert_eq!(u.rank(), 2);
+        assert_eq!(DMatrix::<f32>::zeros(3, 2).rank(), 0);
+    }
+
+    #[test]
+    fn test_large_system() {
+        let n = 60;
+        let u = DMatrix::from_fn(n, n, |j, i| {
+            if i == j {
+                4.
+            } else if i + 1 == j || j + 1 == i {
+                -1.
+            } else {
+                0.
+            }
+        });
+        let inv = u.inverse().unwrap();
+        assert!(dmatrices_are_equal(&u.mul_mat(&inv), &DMatrix::identity(n)));
+        assert_eq!(u.rank(), n);
+    }
+
+    #[test]
+    fn test_fixed_conversions() {
+        let m = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        let d = DMatrix::from(m.clone());
+        assert_eq!(d.shape(), (2, 3));
+        assert_eq!(d[(1, 2)], 6.);
+        assert_eq!(Matrix::<f32, 2, 3>::try_from(d.clone()), Ok(m));
+        assert_eq!(
+            Matrix::<f32, 3, 2>::try_from(d),
+            Err(String::from("Expected 3 rows but got 2"))
+        );
+    }
+}
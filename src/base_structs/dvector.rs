@@ -0,0 +1,234 @@
+use crate::base_structs::vector::Vector;
+use crate::num_traits::scalar::Scalar;
+use std::fmt::Display;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+
+//heap allocated vector whose length is only known at runtime
+#[derive(PartialEq, Debug, Clone)]
+pub struct DVector<T> {
+    data: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for DVector<T> {
+    fn from(d: Vec<T>) -> DVector<T> {
+        DVector { data: d }
+    }
+}
+
+impl<T: Clone, const N: usize> From<Vector<T, N>> for DVector<T> {
+    fn from(v: Vector<T, N>) -> DVector<T> {
+        DVector { data: v.as_vec() }
+    }
+}
+
+impl<T, const N: usize> TryFrom<DVector<T>> for Vector<T, N> {
+    type Error = String;
+    fn try_from(v: DVector<T>) -> Result<Vector<T, N>, String> {
+        Vector::try_from(v.data)
+    }
+}
+
+impl<T> DVector<T> {
+    pub fn from_fn<F: FnMut(usize) -> T>(n: usize, f: F) -> DVector<T> {
+        DVector {
+            data: (0..n).map(f).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data.as_mut_slice()
+    }
+}
+
+impl<T: Clone> DVector<T> {
+    pub fn as_vec(&self) -> Vec<T> {
+        self.data.clone()
+    }
+}
+
+impl<T: Scalar> DVector<T> {
+    pub fn zeros(n: usize) -> DVector<T> {
+        DVector {
+            data: vec![T::zero(); n],
+        }
+    }
+
+    //standard basis vector of length n: one at index i, zero elsewhere
+    pub fn unit(n: usize, i: usize) -> DVector<T> {
+        if i >= n {
+            panic!("DVector: index out of bound");
+        }
+        DVector::from_fn(n, |k| if k == i { T::one() } else { T::zero() })
+    }
+}
+
+impl<T> Index<usize> for DVector<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        if index >= self.data.len() {
+            panic!("DVector: index out of bound");
+        }
+        &self.data[index]
+    }
+}
+
+impl<T> IndexMut<usize> for DVector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        if index >= self.data.len() {
+            panic!("DVector: index out of bound");
+        }
+        &mut self.data[index]
+    }
+}
+
+impl<T: Scalar> DVector<T> {
+    fn check_len(&self, v: &DVector<T>) {
+        if self.len() != v.len() {
+            panic!("DVector: length mismatch, {} and {}", self.len(), v.len());
+        }
+    }
+
+    pub fn add(&mut self, v: &DVector<T>) {
+        self.check_len(v);
+        for (item1, item2) in self.data.iter_mut().zip(v.data.iter()) {
+            *item1 = *item1 + *item2;
+        }
+    }
+
+    pub fn sub(&mut self, v: &DVector<T>) {
+        self.check_len(v);
+        for (item1, item2) in self.data.iter_mut().zip(v.data.iter()) {
+            *item1 = *item1 - *item2;
+        }
+    }
+
+    pub fn scl(&mut self, a: T) {
+        for item in self.data.iter_mut() {
+            *item = *item * a;
+        }
+    }
+
+    pub fn dot(&self, v: &DVector<T>) -> T {
+        self.check_len(v);
+        let mut res = T::zero();
+        for (item1, item2) in self.data.iter().zip(v.data.iter()) {
+            res = res + *item1 * *item2;
+        }
+        res
+    }
+
+    //euclidean length, same as Vector::abs
+    pub fn abs(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalize(&self) -> DVector<T> {
+        self.clone() / self.abs()
+    }
+}
+
+impl<T: Scalar> Add<DVector<T>> for DVector<T> {
+    type Output = DVector<T>;
+    fn add(mut self, rhs: DVector<T>) -> DVector<T> {
+        DVector::add(&mut self, &rhs);
+        self
+    }
+}
+
+impl<T: Scalar> Sub<DVector<T>> for DVector<T> {
+    type Output = DVector<T>;
+    fn sub(mut self, rhs: DVector<T>) -> DVector<T> {
+        DVector::sub(&mut self, &rhs);
+        self
+    }
+}
+
+impl<T: Scalar> Mul<T> for DVector<T> {
+    type Output = DVector<T>;
+    fn mul(mut self, rhs: T) -> DVector<T> {
+        self.scl(rhs);
+        self
+    }
+}
+
+impl<T: Scalar> Div<T> for DVector<T> {
+    type Output = DVector<T>;
+    fn div(self, rhs: T) -> DVector<T> {
+        DVector {
+            data: self.data.iter().map(|item| *item / rhs).collect(),
+        }
+    }
+}
+
+impl<T: Display> Display for DVector<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "[")?;
+        for (i, item) in self.data.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        write!(f, "]")
+    }
+}
+
+#[cfg(test)]
+mod dynamic_vector {
+    use super::DVector;
+    use crate::base_structs::vector::Vector;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut u = DVector::from(vec![2., 3.]);
+        let v = DVector::from(vec![5., 7.]);
+        u.add(&v);
+        assert_eq!(u, DVector::from(vec![7., 10.]));
+        u.sub(&v);
+        assert_eq!(u, DVector::from(vec![2., 3.]));
+        u.scl(2.);
+        assert_eq!(u, DVector::from(vec![4., 6.]));
+        assert_eq!(u.clone() + v.clone(), DVector::from(vec![9., 13.]));
+        assert_eq!(u - v, DVector::from(vec![-1., -1.]));
+    }
+
+    #[test]
+    fn test_dot_and_abs() {
+        let u = DVector::from(vec![-1., 6.]);
+        let v = DVector::from(vec![3., 2.]);
+        assert_eq!(u.dot(&v), 9.0);
+        assert_eq!(DVector::from(vec![3., 4.]).abs(), 5.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "DVector: length mismatch, 2 and 3")]
+    fn test_length_mismatch() {
+        let u = DVector::from(vec![1., 2.]);
+        let v = DVector::from(vec![1., 2., 3.]);
+        u.dot(&v);
+    }
+
+    #[test]
+    fn test_fixed_conversions() {
+        let v = Vector::from([1., 2., 3.]);
+        let d = DVector::from(v.clone());
+        assert_eq!(d.len(), 3);
+        assert_eq!(Vector::<f32, 3>::try_from(d.clone()), Ok(v));
+        assert_eq!(
+            Vector::<f32, 2>::try_from(d),
+            Err(String::from("Expected 2 elements but got 3"))
+        );
+    }
+}
@@ -0,0 +1,339 @@
+use crate::base_structs::dmatrix::DMatrix;
+use crate::num_traits::modp::ModP;
+use crate::num_traits::ring::Ring;
+use std::fmt::Display;
+use std::ops::{Add, Mul};
+
+//matrix over gf(2) with every row packed into u64 words, bit c % 64 of word c / 64 is column c
+//addition is xor and multiplication is and, so row operations work on 64 entries at once
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Gf2Matrix {
+    bits: Vec<u64>,
+    rows: usize,
+    cols: usize,
+    words: usize,
+}
+
+impl Gf2Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Gf2Matrix {
+        let words = cols.div_ceil(64);
+        Gf2Matrix {
+            bits: vec![0; rows * words],
+            rows,
+            cols,
+            words,
+        }
+    }
+
+    pub fn identity(n: usize) -> Gf2Matrix {
+        Gf2Matrix::from_fn(n, n, |r, c| r == c)
+    }
+
+    pub fn from_fn<F: FnMut(usize, usize) -> bool>(
+        rows: usize,
+        cols: usize,
+        mut f: F,
+    ) -> Gf2Matrix {
+        let mut m = Gf2Matrix::zeros(rows, cols);
+        for r in 0..rows {
+            for c in 0..cols {
+                if f(r, c) {
+                    m.set(r, c, true);
+                }
+            }
+        }
+        m
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.cols
+    }
+
+    fn check(&self, r: usize, c: usize) {
+        if r >= self.rows || c >= self.cols {
+            panic!("Gf2Matrix: index out of bound");
+        }
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> bool {
+        self.check(r, c);
+        self.bits[r * self.words + c / 64] >> (c % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, r: usize, c: usize, v: bool) {
+        self.check(r, c);
+        let word = &mut self.bits[r * self.words + c / 64];
+        if v {
+            *word |= 1 << (c % 64);
+        } else {
+            *word &= !(1 << (c % 64));
+        }
+    }
+
+    fn row(&self, r: usize) -> &[u64] {
+        &self.bits[r * self.words..(r + 1) * self.words]
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for w in 0..self.words {
+            self.bits.swap(a * self.words + w, b * self.words + w);
+        }
+    }
+
+    //row target += row source
+    fn add_row(&mut self, target: usize, source: usize) {
+        for w in 0..self.words {
+            let s = self.bits[source * self.words + w];
+            self.bits[target * self.words + w] ^= s;
+        }
+    }
+
+    pub fn transpose(&self) -> Gf2Matrix {
+        Gf2Matrix::from_fn(self.cols, self.rows, |r, c| self.get(c, r))
+    }
+
+    //gauss-jordan, the only non-zero pivot is 1 so no scaling is needed
+    //returns the reduced matrix and the pivot columns
+    fn reduce(&self) -> (Gf2Matrix, Vec<usize>) {
+        let mut m = self.clone();
+        let mut pivots = Vec::new();
+        for col in 0..self.cols {
+            let lead = pivots.len();
+            if lead == self.rows {
+                break;
+            }
+            let p = match (lead..self.rows).find(|r| m.get(*r, col)) {
+                Some(p) => p,
+                None => continue,
+            };
+            m.swap_rows(lead, p);
+            for r in 0..self.rows {
+                if r != lead && m.get(r, col) {
+                    m.add_row(r, lead);
+                }
+            }
+            pivots.push(col);
+        }
+        (m, pivots)
+    }
+
+    //reduced row echelon form
+    pub fn row_echelon(&self) -> Gf2Matrix {
+        self.reduce().0
+    }
+
+    pub fn rank(&self) -> usize {
+        self.reduce().1.len()
+    }
+
+    //the only values are 0 and 1, and 1 exactly when the matrix is invertible
+    pub fn determinant(&self) -> bool {
+        if self.rows != self.cols {
+            panic!("Gf2Matrix: determinant requires a square matrix");
+        }
+        self.rank() == self.rows
+    }
+
+    //reduces [self | i] to [i | self^-1]
+    pub fn inverse(&self) -> Result<Gf2Matrix, String> {
+        if self.rows != self.cols {
+            return Err(String::from("matrix is not square"));
+        }
+        let n = self.rows;
+        let aug = Gf2Matrix::from_fn(
+            n,
+            2 * n,
+            |r, c| {
+                if c < n {
+                    self.get(r, c)
+                } else {
+                    c - n == r
+                }
+            },
+        );
+        let (reduced, pivots) = aug.reduce();
+        if pivots.len() < n || pivots[n - 1] >= n {
+            return Err(String::from("matrix is singular"));
+        }
+        Ok(Gf2Matrix::from_fn(n, n, |r, c| reduced.get(r, c + n)))
+    }
+
+    //basis of the null space as rows, x with self x = 0; for a generator matrix g
+    //this gives a parity check matrix h with g h^T = 0
+    pub fn kernel(&self) -> Gf2Matrix {
+        let (reduced, pivots) = self.reduce();
+        let free: Vec<usize> = (0..self.cols).filter(|c| !pivots.contains(c)).collect();
+        Gf2Matrix::from_fn(free.len(), self.cols, |k, c| {
+            if c == free[k] {
+                true
+            } else {
+                //pivot variables are the sum of the free ones in their row
+                pivots
+                    .iter()
+                    .position(|p| *p == c)
+                    .is_some_and(|r| reduced.get(r, free[k]))
+            }
+        })
+    }
+}
+
+impl Add for Gf2Matrix {
+    type Output = Gf2Matrix;
+
+    fn add(mut self, rhs: Gf2Matrix) -> Gf2Matrix {
+        if (self.rows, self.cols) != (rhs.rows, rhs.cols) {
+            panic!(
+                "Gf2Matrix: shape mismatch, {:?} and {:?}",
+                (self.rows, self.cols),
+                (rhs.rows, rhs.cols)
+            );
+        }
+        for (a, b) in self.bits.iter_mut().zip(rhs.bits.iter()) {
+            *a ^= b;
+        }
+        self
+    }
+}
+
+impl Mul for Gf2Matrix {
+    type Output = Gf2Matrix;
+
+    //row i of the product is the xor of the rows of rhs selected by row i of self
+    fn mul(self, rhs: Gf2Matrix) -> Gf2Matrix {
+        if self.cols != rhs.rows {
+            panic!(
+                "Gf2Matrix: shape mismatch, {:?} and {:?}",
+                (self.rows, self.cols),
+                (rhs.rows, rhs.cols)
+            );
+        }
+        let mut res = Gf2Matrix::zeros(self.rows, rhs.cols);
+        for r in 0..self.rows {
+            for k in 0..self.cols {
+                if self.get(r, k) {
+                    for (w, b) in rhs.row(k).iter().enumerate() {
+                        res.bits[r * res.words + w] ^= b;
+                    }
+                }
+            }
+        }
+        res
+    }
+}
+
+impl From<&DMatrix<ModP<2>>> for Gf2Matrix {
+    fn from(m: &DMatrix<ModP<2>>) -> Gf2Matrix {
+        Gf2Matrix::from_fn(m.nrows(), m.ncols(), |r, c| m[(r, c)].value() == 1)
+    }
+}
+
+impl From<&Gf2Matrix> for DMatrix<ModP<2>> {
+    fn from(m: &Gf2Matrix) -> DMatrix<ModP<2>> {
+        DMatrix::from_fn(m.rows, m.cols, |r, c| {
+            if m.get(r, c) {
+                ModP::one()
+            } else {
+                ModP::zero()
+            }
+        })
+    }
+}
+
+impl Display for Gf2Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for r in 0..self.rows {
+            if r != 0 {
+                writeln!(f)?;
+            }
+            for c in 0..self.cols {
+                write!(f, "{}", if self.get(r, c) { 1 } else { 0 })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod binary_field {
+    use super::*;
+
+    fn gf2(rows: &[&str]) -> Gf2Matrix {
+        let cols = rows.first().map_or(0, |r| r.len());
+        Gf2Matrix::from_fn(rows.len(), cols, |r, c| rows[r].as_bytes()[c] == b'1')
+    }
+
+    #[test]
+    fn test_get_set_and_display() {
+        let mut m = Gf2Matrix::zeros(2, 70);
+        m.set(1, 65, true);
+        m.set(0, 3, true);
+        assert!(m.get(1, 65) && m.get(0, 3) && !m.get(0, 65));
+        m.set(1, 65, false);
+        assert!(!m.get(1, 65));
+        assert_eq!(format!("{}", gf2(&["101", "010"])), "101\n010");
+    }
+
+    #[test]
+    fn test_add_and_mul() {
+        let a = gf2(&["110", "011"]);
+        let b = gf2(&["101", "111"]);
+        assert_eq!(a.clone() + b.clone(), gf2(&["011", "100"]));
+        assert_eq!(a.clone() + a.clone(), Gf2Matrix::zeros(2, 3));
+        let c = gf2(&["10", "11", "01"]);
+        assert_eq!(a * c, gf2(&["01", "10"]));
+    }
+
+    #[test]
+    fn test_elimination() {
+        let m = gf2(&["110", "011", "101"]);
+        //the rows sum to zero
+        assert_eq!(m.rank(), 2);
+        assert!(!m.determinant());
+        assert_eq!(m.row_echelon(), gf2(&["101", "011", "000"]));
+        assert_eq!(m.inverse(), Err(String::from("matrix is singular")));
+        let m = gf2(&["110", "011", "001"]);
+        assert!(m.determinant());
+        let inv = m.inverse().unwrap();
+        assert_eq!(inv.clone() * m.clone(), Gf2Matrix::identity(3));
+        assert_eq!(m * inv, Gf2Matrix::identity(3));
+    }
+
+    #[test]
+    fn test_wide_rows() {
+        //more than one word per row
+        let n = 100;
+        let m = Gf2Matrix::from_fn(n, n, |r, c| c >= r);
+        let inv = m.inverse().unwrap();
+        assert_eq!(m * inv.clone(), Gf2Matrix::identity(n));
+        assert!(inv.get(0, 0) && inv.get(0, 1) && !inv.get(0, 2));
+    }
+
+    #[test]
+    fn test_hamming_code() {
+        //generator of the [7, 4] hamming code
+        let g = gf2(&["1000110", "0100101", "0010011", "0001111"]);
+        let h = g.kernel();
+        assert_eq!(h.nrows(), 3);
+        assert_eq!(h.rank(), 3);
+        assert_eq!(g.clone() * h.transpose(), Gf2Matrix::zeros(4, 3));
+        //encode 1011 and flip one bit, the syndrome is non-zero
+        let msg = gf2(&["1011"]);
+        let mut word = msg * g;
+        assert_eq!(word.clone() * h.transpose(), Gf2Matrix::zeros(1, 3));
+        word.set(0, 2, !word.get(0, 2));
+        assert_ne!(word * h.transpose(), Gf2Matrix::zeros(1, 3));
+    }
+
+    #[test]
+    fn test_matches_modp() {
+        let m = gf2(&["1101", "0111", "1110", "0001"]);
+        let d = DMatrix::from(&m);
+        assert_eq!(d.rank(), m.rank());
+        assert_eq!(d.determinant() == ModP::one(), m.determinant());
+        assert_eq!(Gf2Matrix::from(&d.inverse().unwrap()), m.inverse().unwrap());
+    }
+}
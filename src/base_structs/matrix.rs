@@ -1,5 +1,5 @@
 use crate::base_structs::vector::Vector;
-use crate::num_traits::scalar::Scalar;
+use crate::num_traits::ring::Ring;
 use std::clone::Clone;
 use std::convert::TryInto;
 use std::fmt::Debug;
@@ -26,6 +26,108 @@ impl<T: Clone, const M: usize, const N: usize> From<[[T; N]; M]> for Matrix<T, M
     }
 }
 
+//sizes read at runtime are checked against the dimensions of the matrix
+impl<T, const M: usize, const N: usize> TryFrom<Vec<Vec<T>>> for Matrix<T, M, N> {
+    type Error = String;
+    fn try_from(d: Vec<Vec<T>>) -> Result<Matrix<T, M, N>, String> {
+        if d.len() != M {
+            return Err(format!("Expected {} rows but got {}", M, d.len()));
+        }
+        for (j, row) in d.iter().enumerate() {
+            if row.len() != N {
+                return Err(format!(
+                    "Expected row {} to have {} columns but it has {}",
+                    j,
+                    N,
+                    row.len()
+                ));
+            }
+        }
+        Ok(Matrix { data: d })
+    }
+}
+
+//the slice is read in row-major order
+impl<T: Clone, const M: usize, const N: usize> TryFrom<&[T]> for Matrix<T, M, N> {
+    type Error = String;
+    fn try_from(s: &[T]) -> Result<Matrix<T, M, N>, String> {
+        Matrix::from_row_slice(s)
+    }
+}
+
+impl<T: Clone, const M: usize, const N: usize> Matrix<T, M, N> {
+    fn check_slice_len(s: &[T]) -> Result<(), String> {
+        if s.len() != M * N {
+            return Err(format!("Expected {} elements but got {}", M * N, s.len()));
+        }
+        Ok(())
+    }
+
+    //[a, b, c, d] becomes [[a, b], [c, d]]
+    pub fn from_row_slice(s: &[T]) -> Result<Matrix<T, M, N>, String> {
+        Matrix::<T, M, N>::check_slice_len(s)?;
+        Ok(Matrix::from_fn(|j, i| s[j * N + i].clone()))
+    }
+
+    //[a, b, c, d] becomes [[a, c], [b, d]]
+    pub fn from_column_slice(s: &[T]) -> Result<Matrix<T, M, N>, String> {
+        Matrix::<T, M, N>::check_slice_len(s)?;
+        Ok(Matrix::from_fn(|j, i| s[i * M + j].clone()))
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    //builds the matrix by calling f(row, col) for every element
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(mut f: F) -> Matrix<T, M, N> {
+        let mut d = Vec::with_capacity(M);
+        for j in 0..M {
+            let mut v = Vec::with_capacity(N);
+            for i in 0..N {
+                v.push(f(j, i));
+            }
+            d.push(v);
+        }
+        Matrix { data: d }
+    }
+}
+
+impl<T: Ring, const M: usize, const N: usize> Matrix<T, M, N> {
+    pub fn zeros() -> Matrix<T, M, N> {
+        Matrix::from_fn(|_, _| T::zero())
+    }
+
+    pub fn ones() -> Matrix<T, M, N> {
+        Matrix::from_fn(|_, _| T::one())
+    }
+
+    //each vector becomes one row of the matrix
+    pub fn from_rows(rows: &[Vector<T, N>]) -> Matrix<T, M, N> {
+        if rows.len() != M {
+            panic!("Expected {} rows but got {}", M, rows.len());
+        }
+        Matrix::from_fn(|j, i| rows[j][i])
+    }
+
+    //each vector becomes one column of the matrix
+    pub fn from_cols(cols: &[Vector<T, M>]) -> Matrix<T, M, N> {
+        if cols.len() != N {
+            panic!("Expected {} columns but got {}", N, cols.len());
+        }
+        Matrix::from_fn(|j, i| cols[i][j])
+    }
+}
+
+impl<T: Ring, const M: usize> TMatrix<T, M> {
+    pub fn identity() -> TMatrix<T, M> {
+        Matrix::from_fn(|j, i| if i == j { T::one() } else { T::zero() })
+    }
+
+    //square matrix with the elements of d on the diagonal and zero elsewhere
+    pub fn from_diagonal(d: &Vector<T, M>) -> TMatrix<T, M> {
+        Matrix::from_fn(|j, i| if i == j { d[i] } else { T::zero() })
+    }
+}
+
 impl<T: Debug, const M: usize, const N: usize> Matrix<T, M, N> {
     pub fn out(&self) {
         for item in self.data.iter() {
@@ -34,7 +136,7 @@ impl<T: Debug, const M: usize, const N: usize> Matrix<T, M, N> {
     }
 }
 
-impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
+impl<T: Ring, const M: usize, const N: usize> Matrix<T, M, N> {
     pub fn add(&mut self, v: &Matrix<T, M, N>) {
         let mut res = Vec::new();
         for j in 0..M {
@@ -52,7 +154,7 @@ impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
     }
 }
 
-impl<T: Scalar, const M: usize, const N: usize> Add<Matrix<T, M, N>> for Matrix<T, M, N> {
+impl<T: Ring, const M: usize, const N: usize> Add<Matrix<T, M, N>> for Matrix<T, M, N> {
     type Output = Matrix<T, M, N>;
     fn add(self, v: Matrix<T, M, N>) -> Matrix<T, M, N> {
         let mut res = Vec::new();
@@ -71,7 +173,7 @@ impl<T: Scalar, const M: usize, const N: usize> Add<Matrix<T, M, N>> for Matrix<
     }
 }
 
-impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
+impl<T: Ring, const M: usize, const N: usize> Matrix<T, M, N> {
     pub fn sub(&mut self, v: &Matrix<T, M, N>) {
         let mut res = Vec::new();
         for j in 0..M {
@@ -89,7 +191,7 @@ impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
     }
 }
 
-impl<T: Scalar, const M: usize, const N: usize> Sub<Matrix<T, M, N>> for Matrix<T, M, N> {
+impl<T: Ring, const M: usize, const N: usize> Sub<Matrix<T, M, N>> for Matrix<T, M, N> {
     type Output = Matrix<T, M, N>;
     fn sub(self, v: Matrix<T, M, N>) -> Matrix<T, M, N> {
         let mut res = Vec::new();
@@ -108,7 +210,7 @@ impl<T: Scalar, const M: usize, const N: usize> Sub<Matrix<T, M, N>> for Matrix<
     }
 }
 
-impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
+impl<T: Ring, const M: usize, const N: usize> Matrix<T, M, N> {
     pub fn scl(&mut self, a: T) {
         let mut res = Vec::new();
         for j in 0..M {
@@ -123,7 +225,7 @@ impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
     }
 }
 
-impl<T: Scalar, const M: usize, const N: usize, const H: usize> Mul<Matrix<T, N, H>>
+impl<T: Ring, const M: usize, const N: usize, const H: usize> Mul<Matrix<T, N, H>>
     for Matrix<T, M, N>
 {
     type Output = Matrix<T, M, H>;
@@ -144,7 +246,7 @@ impl<T: Scalar, const M: usize, const N: usize, const H: usize> Mul<Matrix<T, N,
     }
 }
 
-impl<T: Scalar, const M: usize, const N: usize> Mul<T> for Matrix<T, M, N> {
+impl<T: Ring, const M: usize, const N: usize> Mul<T> for Matrix<T, M, N> {
     type Output = Matrix<T, M, N>;
     fn mul(self, rhs: T) -> Matrix<T, M, N> {
         let mut res = Vec::new();
@@ -160,7 +262,7 @@ impl<T: Scalar, const M: usize, const N: usize> Mul<T> for Matrix<T, M, N> {
     }
 }
 
-impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
+impl<T: Ring, const M: usize, const N: usize> Matrix<T, M, N> {
     pub fn as_arr(&mut self) -> [[T; N]; M] {
         let mut res = [[T::zero(); N]; M];
         for j in 0..M {
@@ -172,7 +274,7 @@ impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
     }
 }
 
-impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
+impl<T: Ring, const M: usize, const N: usize> Matrix<T, M, N> {
     pub fn as_vec(&self) -> Vec<Vec<T>> {
         self.data.clone()
     }
@@ -183,7 +285,7 @@ fn vec_to_arr<T, const N: usize>(v: Vec<T>) -> [T; N] {
         .unwrap_or_else(|v: Vec<T>| panic!("Expected a Vec of length {} but it was {}", N, v.len()))
 }
 
-impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
+impl<T: Ring, const M: usize, const N: usize> Matrix<T, M, N> {
     pub fn as_vector(&self, h: usize) -> Vector<T, N> {
         let arr = vec_to_arr(self.data[h].clone());
         Vector::from(arr)
@@ -209,3 +311,107 @@ impl<T: Display, const M: usize, const N: usize> Display for Matrix<T, M, N> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod constructors {
+    use super::*;
+
+    #[test]
+    fn test_zeros_and_ones() {
+        assert_eq!(
+            Matrix::<f32, 2, 3>::zeros(),
+            Matrix::from([[0., 0., 0.], [0., 0., 0.]])
+        );
+        assert_eq!(
+            Matrix::<f32, 2, 2>::ones(),
+            Matrix::from([[1., 1.], [1., 1.]])
+        );
+    }
+
+    #[test]
+    fn test_identity() {
+        assert_eq!(
+            TMatrix3::<f32>::identity(),
+            Matrix::from([[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]])
+        );
+        assert_eq!(TMatrix::<f32, 1>::identity(), Matrix::from([[1.]]));
+    }
+
+    #[test]
+    fn test_from_diagonal() {
+        let d = Vector::from([2., -1., 3.]);
+        assert_eq!(
+            TMatrix3::from_diagonal(&d),
+            Matrix::from([[2., 0., 0.], [0., -1., 0.], [0., 0., 3.]])
+        );
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let m = Matrix::<f32, 2, 3>::from_fn(|r, c| (r * 10 + c) as f32);
+        assert_eq!(m, Matrix::from([[0., 1., 2.], [10., 11., 12.]]));
+    }
+
+    #[test]
+    fn test_from_rows_and_cols() {
+        let a = Vector::from([1., 2., 3.]);
+        let b = Vector::from([4., 5., 6.]);
+        let rows = Matrix::<f32, 2, 3>::from_rows(&[a.clone(), b.clone()]);
+        assert_eq!(rows, Matrix::from([[1., 2., 3.], [4., 5., 6.]]));
+        let cols = Matrix::<f32, 3, 2>::from_cols(&[a, b]);
+        assert_eq!(cols, Matrix::from([[1., 4.], [2., 5.], [3., 6.]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 3 rows but got 2")]
+    fn test_from_rows_panic() {
+        let a = Vector::from([1., 2.]);
+        Matrix::<f32, 3, 2>::from_rows(&[a.clone(), a]);
+    }
+}
+
+#[cfg(test)]
+mod try_from {
+    use super::*;
+
+    #[test]
+    fn test_try_from_nested_vec() {
+        let m = Matrix::<f32, 2, 2>::try_from(vec![vec![1., 2.], vec![3., 4.]]);
+        assert_eq!(m, Ok(Matrix::from([[1., 2.], [3., 4.]])));
+    }
+
+    #[test]
+    fn test_try_from_nested_vec_wrong_rows() {
+        let m = Matrix::<f32, 3, 2>::try_from(vec![vec![1., 2.], vec![3., 4.]]);
+        assert_eq!(m, Err(String::from("Expected 3 rows but got 2")));
+    }
+
+    #[test]
+    fn test_try_from_nested_vec_ragged() {
+        let m = Matrix::<f32, 2, 2>::try_from(vec![vec![1., 2.], vec![3.]]);
+        assert_eq!(
+            m,
+            Err(String::from(
+                "Expected row 1 to have 2 columns but it has 1"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_try_from_slice() {
+        let s = [1., 2., 3., 4., 5., 6.];
+        let m = Matrix::<f32, 2, 3>::try_from(&s[..]);
+        assert_eq!(m, Ok(Matrix::from([[1., 2., 3.], [4., 5., 6.]])));
+        let m = Matrix::<f32, 2, 3>::from_column_slice(&s);
+        assert_eq!(m, Ok(Matrix::from([[1., 3., 5.], [2., 4., 6.]])));
+    }
+
+    #[test]
+    fn test_try_from_slice_wrong_len() {
+        let s = [1., 2., 3.];
+        let m = Matrix::<f32, 2, 2>::from_row_slice(&s);
+        assert_eq!(m, Err(String::from("Expected 4 elements but got 3")));
+        let m = Matrix::<f32, 2, 2>::from_column_slice(&s);
+        assert_eq!(m, Err(String::from("Expected 4 elements but got 3")));
+    }
+}
@@ -1,2 +1,7 @@
+pub mod dmatrix;
+pub mod dvector;
+pub mod gf2;
 pub mod matrix;
-pub mod vector;
\ No newline at end of file
+pub mod quaternion;
+pub mod sparse;
+pub mod vector;
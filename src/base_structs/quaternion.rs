@@ -0,0 +1,339 @@
+use crate::base_structs::matrix::{Matrix, TMatrix3, TMatrix4};
+use crate::base_structs::vector::{TVector3, Vector};
+use crate::num_traits::real::Real;
+use crate::num_traits::scalar::Scalar;
+use std::fmt::Display;
+use std::ops::{Add, Mul, Neg, Sub};
+
+//w + xi + yj + zk, unit quaternions represent rotations without gimbal lock
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Quaternion<T> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Scalar> Quaternion<T> {
+    pub fn new(w: T, x: T, y: T, z: T) -> Quaternion<T> {
+        Quaternion { w, x, y, z }
+    }
+
+    //no rotation
+    pub fn identity() -> Quaternion<T> {
+        Quaternion::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+
+    //pure quaternion with zero real part, used to rotate vectors
+    pub fn from_vector(v: &TVector3<T>) -> Quaternion<T> {
+        Quaternion::new(T::zero(), v[0], v[1], v[2])
+    }
+
+    pub fn vector(&self) -> TVector3<T> {
+        Vector::from([self.x, self.y, self.z])
+    }
+
+    pub fn conjugate(&self) -> Quaternion<T> {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn dot(&self, q: &Quaternion<T>) -> T {
+        self.w * q.w + self.x * q.x + self.y * q.y + self.z * q.z
+    }
+
+    //length of the quaternion, same naming as Vector::abs
+    pub fn abs(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalize(&self) -> Quaternion<T> {
+        *self * (T::one() / self.abs())
+    }
+
+    pub fn inverse(&self) -> Result<Quaternion<T>, String> {
+        let n = self.dot(self);
+        if n == T::zero() {
+            return Err(String::from("quaternion is zero"));
+        }
+        Ok(self.conjugate() * (T::one() / n))
+    }
+
+    //q v q^-1, q is expected to be normalized
+    pub fn rotate(&self, v: &TVector3<T>) -> TVector3<T> {
+        (*self * Quaternion::from_vector(v) * self.conjugate()).vector()
+    }
+
+    //rotation matrix for column vectors, the same layout as TMatrix4::rotation_x and friends
+    pub fn to_matrix3(&self) -> TMatrix3<T> {
+        let q = self.normalize();
+        let (w, x, y, z) = (q.w, q.x, q.y, q.z);
+        let o = T::one();
+        let two = o + o;
+        Matrix::from([
+            [
+                o - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                o - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                o - two * (x * x + y * y),
+            ],
+        ])
+    }
+
+    pub fn to_matrix4(&self) -> TMatrix4<T> {
+        let r = self.to_matrix3().as_vec();
+        Matrix::from_fn(|j, i| {
+            if j < 3 && i < 3 {
+                r[j][i]
+            } else if i == j {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    //inverse of to_matrix3, picks the largest of w, x, y, z to stay numerically stable
+    pub fn from_matrix3(m: &TMatrix3<T>) -> Quaternion<T> {
+        let d = m.as_vec();
+        let o = T::one();
+        let two = o + o;
+        let trace = d[0][0] + d[1][1] + d[2][2];
+        let q = if trace > T::zero() {
+            let s = (trace + o).sqrt() * two;
+            Quaternion::new(
+                s / (two + two),
+                (d[2][1] - d[1][2]) / s,
+                (d[0][2] - d[2][0]) / s,
+                (d[1][0] - d[0][1]) / s,
+            )
+        } else if d[0][0] > d[1][1] && d[0][0] > d[2][2] {
+            let s = (o + d[0][0] - d[1][1] - d[2][2]).sqrt() * two;
+            Quaternion::new(
+                (d[2][1] - d[1][2]) / s,
+                s / (two + two),
+                (d[0][1] + d[1][0]) / s,
+                (d[0][2] + d[2][0]) / s,
+            )
+        } else if d[1][1] > d[2][2] {
+            let s = (o + d[1][1] - d[0][0] - d[2][2]).sqrt() * two;
+            Quaternion::new(
+                (d[0][2] - d[2][0]) / s,
+                (d[0][1] + d[1][0]) / s,
+                s / (two + two),
+                (d[1][2] + d[2][1]) / s,
+            )
+        } else {
+            let s = (o + d[2][2] - d[0][0] - d[1][1]).sqrt() * two;
+            Quaternion::new(
+                (d[1][0] - d[0][1]) / s,
+                (d[0][2] + d[2][0]) / s,
+                (d[1][2] + d[2][1]) / s,
+                s / (two + two),
+            )
+        };
+        q.normalize()
+    }
+
+    //only the upper left 3x3 block is read
+    pub fn from_matrix4(m: &TMatrix4<T>) -> Quaternion<T> {
+        let d = m.as_vec();
+        Quaternion::from_matrix3(&Matrix::from_fn(|j, i| d[j][i]))
+    }
+}
+
+impl<T: Real> Quaternion<T> {
+    //angle in radians, counter-clockwise around the axis
+    pub fn from_axis_angle(axis: &TVector3<T>, angle: T) -> Quaternion<T> {
+        let half = angle / (T::one() + T::one());
+        let k = axis.normalize() * half.sin();
+        Quaternion::new(half.cos(), k[0], k[1], k[2])
+    }
+
+    //the identity has no defined axis, x is returned in that case
+    pub fn to_axis_angle(&self) -> (TVector3<T>, T) {
+        let mut q = self.normalize();
+        if q.w < T::zero() {
+            q = -q;
+        }
+        let v = q.vector();
+        let s = v.abs();
+        let angle = s.atan2(q.w) * (T::one() + T::one());
+        if s == T::zero() {
+            return (Vector::unit(0), angle);
+        }
+        (v / s, angle)
+    }
+
+    //spherical linear interpolation at constant angular speed along the shortest arc
+    //where lerp moves in a straight line, slerp stays on the unit sphere
+    pub fn slerp(&self, q: &Quaternion<T>, t: T) -> Quaternion<T> {
+        let a = self.normalize();
+        let mut b = q.normalize();
+        let mut cos = a.dot(&b);
+        if cos < T::zero() {
+            b = -b;
+            cos = -cos;
+        }
+        //nearly parallel, sin(theta) is too small to divide by
+        if cos > T::from_f32(0.9995) {
+            return (a + (b - a) * t).normalize();
+        }
+        let theta = cos.acos();
+        let sin = theta.sin();
+        let wa = ((T::one() - t) * theta).sin() / sin;
+        let wb = (t * theta).sin() / sin;
+        a * wa + b * wb
+    }
+}
+
+//hamilton product, composes rotations: (p * q) applies q first, then p
+impl<T: Scalar> Mul<Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, q: Quaternion<T>) -> Quaternion<T> {
+        Quaternion::new(
+            self.w * q.w - self.x * q.x - self.y * q.y - self.z * q.z,
+            self.w * q.x + self.x * q.w + self.y * q.z - self.z * q.y,
+            self.w * q.y - self.x * q.z + self.y * q.w + self.z * q.x,
+            self.w * q.z + self.x * q.y - self.y * q.x + self.z * q.w,
+        )
+    }
+}
+
+impl<T: Scalar> Mul<T> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, a: T) -> Quaternion<T> {
+        Quaternion::new(self.w * a, self.x * a, self.y * a, self.z * a)
+    }
+}
+
+impl<T: Scalar> Add<Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn add(self, q: Quaternion<T>) -> Quaternion<T> {
+        Quaternion::new(self.w + q.w, self.x + q.x, self.y + q.y, self.z + q.z)
+    }
+}
+
+impl<T: Scalar> Sub<Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn sub(self, q: Quaternion<T>) -> Quaternion<T> {
+        Quaternion::new(self.w - q.w, self.x - q.x, self.y - q.y, self.z - q.z)
+    }
+}
+
+impl<T: Scalar> Neg for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn neg(self) -> Quaternion<T> {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Display> Display for Quaternion<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "[{}, {}, {}, {}]", self.w, self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod quaternions {
+    use super::*;
+    use crate::transforms::convention::Convention;
+    use crate::utils::comp::{floats_are_equal, matrices_are_equal, vectors_are_equal};
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    fn quats_are_equal(a: &Quaternion<f32>, b: &Quaternion<f32>) -> bool {
+        floats_are_equal(a.w, b.w)
+            && floats_are_equal(a.x, b.x)
+            && floats_are_equal(a.y, b.y)
+            && floats_are_equal(a.z, b.z)
+    }
+
+    #[test]
+    fn test_hamilton_product() {
+        let i = Quaternion::new(0., 1., 0., 0.);
+        let j = Quaternion::new(0., 0., 1., 0.);
+        let k = Quaternion::new(0., 0., 0., 1.);
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+        assert_eq!(i * i, Quaternion::new(-1., 0., 0., 0.));
+        let q = Quaternion::new(1., 2., 3., 4.);
+        let p = Quaternion::new(5., 6., 7., 8.);
+        assert_eq!(q * p, Quaternion::new(-60., 12., 30., 24.));
+    }
+
+    #[test]
+    fn test_conjugate_and_inverse() {
+        let q = Quaternion::new(1., 2., 3., 4.);
+        assert_eq!(q.conjugate(), Quaternion::new(1., -2., -3., -4.));
+        let inv = q.inverse().unwrap();
+        assert!(quats_are_equal(&(q * inv), &Quaternion::identity()));
+        let zero = Quaternion::new(0., 0., 0., 0.);
+        assert_eq!(zero.inverse(), Err(String::from("quaternion is zero")));
+        assert!(floats_are_equal(q.normalize().abs(), 1.));
+    }
+
+    #[test]
+    fn test_rotate() {
+        let q = Quaternion::from_axis_angle(&Vector::from([0., 0., 1.]), FRAC_PI_2);
+        let v = q.rotate(&Vector::from([1., 0., 0.]));
+        assert!(vectors_are_equal(&v, &Vector::from([0., 1., 0.])));
+    }
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let axis = Vector::from([1., -2., 0.5]);
+        let q = Quaternion::from_axis_angle(&axis, 2.5);
+        let expected = TMatrix4::rotation_axis_angle(&axis, 2.5, Convention::ColumnVector);
+        assert!(matrices_are_equal(&q.to_matrix4(), &expected));
+        let back = Quaternion::from_matrix4(&expected);
+        //q and -q are the same rotation
+        assert!(quats_are_equal(&back, &q) || quats_are_equal(&back, &-q));
+        for angle in [0., 0.3, PI - 0.01, PI] {
+            for axis in [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.], [1., 1., 1.]] {
+                let q = Quaternion::from_axis_angle(&Vector::from(axis), angle);
+                let m = q.to_matrix3();
+                assert!(matrices_are_equal(
+                    &Quaternion::from_matrix3(&m).to_matrix3(),
+                    &m
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_axis_angle_round_trip() {
+        let q = Quaternion::from_axis_angle(&Vector::from([0., 3., 0.]), 1.2);
+        let (axis, angle) = q.to_axis_angle();
+        assert!(vectors_are_equal(&axis, &Vector::from([0., 1., 0.])));
+        assert!(floats_are_equal(angle, 1.2));
+        let (axis, angle) = Quaternion::<f32>::identity().to_axis_angle();
+        assert_eq!(axis, Vector::from([1., 0., 0.]));
+        assert_eq!(angle, 0.);
+    }
+
+    #[test]
+    fn test_slerp() {
+        let z = Vector::from([0., 0., 1.]);
+        let a = Quaternion::identity();
+        let b = Quaternion::from_axis_angle(&z, FRAC_PI_2);
+        assert!(quats_are_equal(&a.slerp(&b, 0.), &a));
+        assert!(quats_are_equal(&a.slerp(&b, 1.), &b));
+        let half = a.slerp(&b, 0.5);
+        assert!(quats_are_equal(
+            &half,
+            &Quaternion::from_axis_angle(&z, FRAC_PI_2 / 2.)
+        ));
+        assert!(floats_are_equal(half.abs(), 1.));
+        //takes the short way around when the signs disagree
+        let c = a.slerp(&-b, 0.5);
+        assert!(quats_are_equal(&c, &half));
+    }
+}
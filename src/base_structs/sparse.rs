@@ -0,0 +1,609 @@
+use crate::base_structs::dmatrix::DMatrix;
+use crate::base_structs::dvector::DVector;
+use crate::base_structs::matrix::Matrix;
+use crate::base_structs::vector::Vector;
+use crate::num_traits::scalar::Scalar;
+
+pub type SparseMatrix<T> = CsrMatrix<T>;
+
+//coordinate list used to assemble a sparse matrix one entry at a time
+//duplicated entries are summed when converting to csr or csc
+#[derive(PartialEq, Debug, Clone)]
+pub struct CooMatrix<T> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<(usize, usize, T)>,
+}
+
+impl<T: Scalar> CooMatrix<T> {
+    pub fn new(rows: usize, cols: usize) -> CooMatrix<T> {
+        CooMatrix {
+            rows,
+            cols,
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: usize, col: usize, value: T) {
+        if row >= self.rows || col >= self.cols {
+            panic!("CooMatrix: index out of bound");
+        }
+        self.entries.push((row, col, value));
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.cols
+    }
+
+    //number of pushed entries, duplicates included
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let it = self.entries.iter().map(|&(r, c, v)| (r, c, v));
+        CsrMatrix {
+            c: Compressed::from_triplets(self.rows, self.cols, it),
+        }
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let it = self.entries.iter().map(|&(r, c, v)| (c, r, v));
+        CscMatrix {
+            c: Compressed::from_triplets(self.cols, self.rows, it),
+        }
+    }
+}
+
+//compressed storage shared by csr (outer = rows) and csc (outer = columns)
+#[derive(PartialEq, Debug, Clone)]
+struct Compressed<T> {
+    outer: usize,
+    inner: usize,
+    ptr: Vec<usize>,
+    idx: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T: Scalar> Compressed<T> {
+    fn from_triplets<I: Iterator<Item = (usize, usize, T)>>(
+        outer: usize,
+        inner: usize,
+        it: I,
+    ) -> Compressed<T> {
+        let mut lanes: Vec<Vec<(usize, T)>> = vec![Vec::new(); outer];
+        for (o, i, v) in it {
+            lanes[o].push((i, v));
+        }
+        let mut ptr = Vec::with_capacity(outer + 1);
+        let mut idx = Vec::new();
+        let mut values = Vec::new();
+        ptr.push(0);
+        for lane in lanes.iter_mut() {
+            lane.sort_by_key(|&(i, _)| i);
+            let start = idx.len();
+            for &(i, v) in lane.iter() {
+                if idx.len() > start && idx[idx.len() - 1] == i {
+                    let last = values.len() - 1;
+                    values[last] = values[last] + v;
+                } else {
+                    idx.push(i);
+                    values.push(v);
+                }
+            }
+            ptr.push(idx.len());
+        }
+        let mut res = Compressed {
+            outer,
+            inner,
+            ptr,
+            idx,
+            values,
+        };
+        res.prune();
+        res
+    }
+
+    //drops stored zeros, for example entries that cancelled out when summed
+    fn prune(&mut self) {
+        let mut ptr = Vec::with_capacity(self.outer + 1);
+        let mut idx = Vec::with_capacity(self.idx.len());
+        let mut values = Vec::with_capacity(self.values.len());
+        ptr.push(0);
+        for o in 0..self.outer {
+            for k in self.ptr[o]..self.ptr[o + 1] {
+                if self.values[k] != T::zero() {
+                    idx.push(self.idx[k]);
+                    values.push(self.values[k]);
+                }
+            }
+            ptr.push(idx.len());
+        }
+        self.ptr = ptr;
+        self.idx = idx;
+        self.values = values;
+    }
+
+    fn lane(&self, o: usize) -> (&[usize], &[T]) {
+        let range = self.ptr[o]..self.ptr[o + 1];
+        (&self.idx[range.clone()], &self.values[range])
+    }
+
+    fn get(&self, o: usize, i: usize) -> T {
+        let (idx, values) = self.lane(o);
+        match idx.binary_search(&i) {
+            Ok(k) => values[k],
+            Err(_) => T::zero(),
+        }
+    }
+
+    //same matrix with outer and inner swapped, which is csr <-> csc or a transpose
+    fn swap_axes(&self) -> Compressed<T> {
+        let mut it = Vec::with_capacity(self.values.len());
+        for o in 0..self.outer {
+            let (idx, values) = self.lane(o);
+            for (&i, &v) in idx.iter().zip(values) {
+                it.push((i, o, v));
+            }
+        }
+        Compressed::from_triplets(self.inner, self.outer, it.into_iter())
+    }
+
+    //y = A x when outer indexes the rows of A
+    fn mul_outer(&self, x: &[T]) -> Vec<T> {
+        let mut res = vec![T::zero(); self.outer];
+        for (o, item) in res.iter_mut().enumerate() {
+            let (idx, values) = self.lane(o);
+            for (&i, &v) in idx.iter().zip(values) {
+                *item = *item + v * x[i];
+            }
+        }
+        res
+    }
+
+    //y = A x when outer indexes the columns of A
+    fn mul_inner(&self, x: &[T]) -> Vec<T> {
+        let mut res = vec![T::zero(); self.inner];
+        for (o, &xo) in x.iter().enumerate() {
+            let (idx, values) = self.lane(o);
+            for (&i, &v) in idx.iter().zip(values) {
+                res[i] = res[i] + v * xo;
+            }
+        }
+        res
+    }
+
+    fn from_rows(data: &[Vec<T>], inner: usize) -> Compressed<T> {
+        let mut it = Vec::new();
+        for (o, row) in data.iter().enumerate() {
+            for (i, &v) in row.iter().enumerate() {
+                if v != T::zero() {
+                    it.push((o, i, v));
+                }
+            }
+        }
+        Compressed::from_triplets(data.len(), inner, it.into_iter())
+    }
+}
+
+//compressed sparse row storage, fast row access and matrix-vector products
+#[derive(PartialEq, Debug, Clone)]
+pub struct CsrMatrix<T> {
+    c: Compressed<T>,
+}
+
+//compressed sparse column storage, fast column access
+#[derive(PartialEq, Debug, Clone)]
+pub struct CscMatrix<T> {
+    c: Compressed<T>,
+}
+
+fn check_vec_len(cols: usize, len: usize) {
+    if cols != len {
+        panic!(
+            "SparseMatrix: cannot multiply {} columns by vector of length {}",
+            cols, len
+        );
+    }
+}
+
+//fixed size results take their row count from the caller
+fn check_out_rows(rows: usize, m: usize) {
+    if rows != m {
+        panic!(
+            "SparseMatrix: cannot store {} rows in a result with {} rows",
+            rows, m
+        );
+    }
+}
+
+impl<T: Scalar> CsrMatrix<T> {
+    pub fn nrows(&self) -> usize {
+        self.c.outer
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.c.inner
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows(), self.ncols())
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.c.values.len()
+    }
+
+    pub fn row_ptr(&self) -> &[usize] {
+        &self.c.ptr
+    }
+
+    pub fn col_indices(&self) -> &[usize] {
+        &self.c.idx
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.c.values
+    }
+
+    //column indices and values of the stored entries of row r
+    pub fn row(&self, r: usize) -> (&[usize], &[T]) {
+        if r >= self.nrows() {
+            panic!("SparseMatrix: index out of bound");
+        }
+        self.c.lane(r)
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> T {
+        if r >= self.nrows() || c >= self.ncols() {
+            panic!("SparseMatrix: index out of bound");
+        }
+        self.c.get(r, c)
+    }
+
+    pub fn transpose(&self) -> CsrMatrix<T> {
+        CsrMatrix {
+            c: self.c.swap_axes(),
+        }
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        CscMatrix {
+            c: self.c.swap_axes(),
+        }
+    }
+
+    pub fn mul_vec(&self, rhs: &DVector<T>) -> DVector<T> {
+        check_vec_len(self.ncols(), rhs.len());
+        DVector::from(self.c.mul_outer(rhs.as_slice()))
+    }
+
+    //the number of rows M has to match the sparse matrix
+    pub fn mul_vector<const M: usize, const N: usize>(&self, rhs: &Vector<T, N>) -> Vector<T, M> {
+        check_vec_len(self.ncols(), N);
+        check_out_rows(self.nrows(), M);
+        Vector::try_from(self.c.mul_outer(rhs.as_slice())).unwrap()
+    }
+
+    //sparse times dense, each stored entry scales one row of rhs
+    pub fn mul_dense(&self, rhs: &DMatrix<T>) -> DMatrix<T> {
+        if self.ncols() != rhs.nrows() {
+            panic!(
+                "SparseMatrix: cannot multiply {:?} matrix by {:?} matrix",
+                self.shape(),
+                rhs.shape()
+            );
+        }
+        let mut res = DMatrix::zeros(self.nrows(), rhs.ncols());
+        for r in 0..self.nrows() {
+            let (idx, values) = self.c.lane(r);
+            for (&k, &v) in idx.iter().zip(values) {
+                for (item, b) in res.data[r].iter_mut().zip(rhs.data[k].iter()) {
+                    *item = *item + v * *b;
+                }
+            }
+        }
+        res
+    }
+
+    pub fn mul_mat<const M: usize, const N: usize, const H: usize>(
+        &self,
+        rhs: &Matrix<T, N, H>,
+    ) -> Matrix<T, M, H> {
+        check_out_rows(self.nrows(), M);
+        let res = self.mul_dense(&DMatrix::from(rhs.clone()));
+        Matrix::try_from(res).unwrap()
+    }
+
+    pub fn to_dmatrix(&self) -> DMatrix<T> {
+        let mut res = DMatrix::zeros(self.nrows(), self.ncols());
+        for r in 0..self.nrows() {
+            let (idx, values) = self.c.lane(r);
+            for (&c, &v) in idx.iter().zip(values) {
+                res[(r, c)] = v;
+            }
+        }
+        res
+    }
+
+    pub fn to_dense<const M: usize, const N: usize>(&self) -> Result<Matrix<T, M, N>, String> {
+        Matrix::try_from(self.to_dmatrix())
+    }
+}
+
+impl<T: Scalar> CscMatrix<T> {
+    pub fn nrows(&self) -> usize {
+        self.c.inner
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.c.outer
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows(), self.ncols())
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.c.values.len()
+    }
+
+    pub fn col_ptr(&self) -> &[usize] {
+        &self.c.ptr
+    }
+
+    pub fn row_indices(&self) -> &[usize] {
+        &self.c.idx
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.c.values
+    }
+
+    //row indices and values of the stored entries of column c
+    pub fn col(&self, c: usize) -> (&[usize], &[T]) {
+        if c >= self.ncols() {
+            panic!("SparseMatrix: index out of bound");
+        }
+        self.c.lane(c)
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> T {
+        if r >= self.nrows() || c >= self.ncols() {
+            panic!("SparseMatrix: index out of bound");
+        }
+        self.c.get(c, r)
+    }
+
+    pub fn transpose(&self) -> CscMatrix<T> {
+        CscMatrix {
+            c: self.c.swap_axes(),
+        }
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        CsrMatrix {
+            c: self.c.swap_axes(),
+        }
+    }
+
+    pub fn mul_vec(&self, rhs: &DVector<T>) -> DVector<T> {
+        check_vec_len(self.ncols(), rhs.len());
+        DVector::from(self.c.mul_inner(rhs.as_slice()))
+    }
+
+    pub fn mul_vector<const M: usize, const N: usize>(&self, rhs: &Vector<T, N>) -> Vector<T, M> {
+        check_vec_len(self.ncols(), N);
+        check_out_rows(self.nrows(), M);
+        Vector::try_from(self.c.mul_inner(rhs.as_slice())).unwrap()
+    }
+
+    //sparse times dense, each stored entry (r, k) adds a multiple of row k of rhs to row r
+    pub fn mul_dense(&self, rhs: &DMatrix<T>) -> DMatrix<T> {
+        if self.ncols() != rhs.nrows() {
+            panic!(
+                "SparseMatrix: cannot multiply {:?} matrix by {:?} matrix",
+                self.shape(),
+                rhs.shape()
+            );
+        }
+        let mut res = DMatrix::zeros(self.nrows(), rhs.ncols());
+        for k in 0..self.ncols() {
+            let (idx, values) = self.c.lane(k);
+            for (&r, &v) in idx.iter().zip(values) {
+                for (item, b) in res.data[r].iter_mut().zip(rhs.data[k].iter()) {
+                    *item = *item + v * *b;
+                }
+            }
+        }
+        res
+    }
+
+    pub fn mul_mat<const M: usize, const N: usize, const H: usize>(
+        &self,
+        rhs: &Matrix<T, N, H>,
+    ) -> Matrix<T, M, H> {
+        check_out_rows(self.nrows(), M);
+        let res = self.mul_dense(&DMatrix::from(rhs.clone()));
+        Matrix::try_from(res).unwrap()
+    }
+
+    pub fn to_dmatrix(&self) -> DMatrix<T> {
+        self.to_csr().to_dmatrix()
+    }
+
+    pub fn to_dense<const M: usize, const N: usize>(&self) -> Result<Matrix<T, M, N>, String> {
+        self.to_csr().to_dense()
+    }
+}
+
+impl<T: Scalar> From<&DMatrix<T>> for CsrMatrix<T> {
+    fn from(m: &DMatrix<T>) -> CsrMatrix<T> {
+        CsrMatrix {
+            c: Compressed::from_rows(&m.data, m.ncols()),
+        }
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize> From<&Matrix<T, M, N>> for CsrMatrix<T> {
+    fn from(m: &Matrix<T, M, N>) -> CsrMatrix<T> {
+        CsrMatrix {
+            c: Compressed::from_rows(&m.data, N),
+        }
+    }
+}
+
+impl<T: Scalar> From<&DMatrix<T>> for CscMatrix<T> {
+    fn from(m: &DMatrix<T>) -> CscMatrix<T> {
+        CsrMatrix::from(m).to_csc()
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize> From<&Matrix<T, M, N>> for CscMatrix<T> {
+    fn from(m: &Matrix<T, M, N>) -> CscMatrix<T> {
+        CsrMatrix::from(m).to_csc()
+    }
+}
+
+#[cfg(test)]
+mod compressed {
+    use super::*;
+
+    fn sample() -> CooMatrix<f32> {
+        // [[4, 0, 1],
+        //  [0, 0, 2],
+        //  [3, 5, 0]]
+        let mut coo = CooMatrix::new(3, 3);
+        coo.push(0, 0, 4.);
+        coo.push(2, 1, 5.);
+        coo.push(0, 2, 1.);
+        coo.push(1, 2, 2.);
+        coo.push(2, 0, 3.);
+        coo
+    }
+
+    #[test]
+    fn test_csr_layout() {
+        let csr = sample().to_csr();
+        assert_eq!(csr.row_ptr(), &[0, 2, 3, 5]);
+        assert_eq!(csr.col_indices(), &[0, 2, 2, 0, 1]);
+        assert_eq!(csr.values(), &[4., 1., 2., 3., 5.]);
+        assert_eq!(csr.get(2, 1), 5.);
+        assert_eq!(csr.get(1, 1), 0.);
+    }
+
+    #[test]
+    fn test_csc_layout() {
+        let csc = sample().to_csc();
+        assert_eq!(csc.col_ptr(), &[0, 2, 3, 5]);
+        assert_eq!(csc.row_indices(), &[0, 2, 2, 0, 1]);
+        assert_eq!(csc.values(), &[4., 3., 5., 1., 2.]);
+        assert_eq!(csc.to_csr(), sample().to_csr());
+    }
+
+    #[test]
+    fn test_duplicates_are_summed() {
+        let mut coo = CooMatrix::new(2, 2);
+        coo.push(0, 1, 1.);
+        coo.push(0, 1, 2.);
+        coo.push(1, 0, 1.);
+        coo.push(1, 0, -1.);
+        let csr = coo.to_csr();
+        assert_eq!(csr.nnz(), 1);
+        assert_eq!(csr.get(0, 1), 3.);
+    }
+
+    #[test]
+    fn test_mul_vec() {
+        let x = Vector::from([1., 2., 3.]);
+        let expected = Vector::from([7., 6., 13.]);
+        let csr = sample().to_csr();
+        let csc = sample().to_csc();
+        assert_eq!(csr.mul_vector::<3, 3>(&x), expected);
+        assert_eq!(csc.mul_vector::<3, 3>(&x), expected);
+        let d = DVector::from(x);
+        assert_eq!(csr.mul_vec(&d), DVector::from(expected.clone()));
+        assert_eq!(csc.mul_vec(&d), DVector::from(expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "SparseMatrix: cannot multiply 3 columns by vector of length 2")]
+    fn test_mul_vec_mismatch() {
+        sample().to_csr().mul_vec(&DVector::from(vec![1., 2.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "SparseMatrix: cannot store 3 rows in a result with 2 rows")]
+    fn test_csr_mul_vector_wrong_rows() {
+        let _: Vector<f32, 2> = sample().to_csr().mul_vector(&Vector::from([1., 2., 3.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "SparseMatrix: cannot store 3 rows in a result with 4 rows")]
+    fn test_csc_mul_vector_wrong_rows() {
+        let _: Vector<f32, 4> = sample().to_csc().mul_vector(&Vector::from([1., 2., 3.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "SparseMatrix: cannot store 3 rows in a result with 2 rows")]
+    fn test_mul_mat_wrong_rows() {
+        let rhs: Matrix<f32, 3, 2> = Matrix::zeros();
+        let _: Matrix<f32, 2, 2> = sample().to_csr().mul_mat(&rhs);
+    }
+
+    #[test]
+    fn test_mul_dense() {
+        let csr = sample().to_csr();
+        let b = Matrix::from([[1., 0.], [0., 1.], [1., 1.]]);
+        let res: Matrix<f32, 3, 2> = csr.mul_mat(&b);
+        assert_eq!(res, Matrix::from([[5., 1.], [2., 2.], [3., 5.]]));
+        let csc = sample().to_csc();
+        let res: Matrix<f32, 3, 2> = csc.mul_mat(&b);
+        assert_eq!(res, Matrix::from([[5., 1.], [2., 2.], [3., 5.]]));
+        let d = DMatrix::from(b);
+        assert_eq!(csc.mul_dense(&d), csr.mul_dense(&d));
+    }
+
+    #[test]
+    #[should_panic(expected = "SparseMatrix: cannot store 3 rows in a result with 2 rows")]
+    fn test_csc_mul_mat_wrong_rows() {
+        let rhs: Matrix<f32, 3, 2> = Matrix::zeros();
+        let _: Matrix<f32, 2, 2> = sample().to_csc().mul_mat(&rhs);
+    }
+
+    #[test]
+    #[should_panic(expected = "SparseMatrix: cannot multiply (3, 3) matrix by (2, 2) matrix")]
+    fn test_csc_mul_dense_mismatch() {
+        sample().to_csc().mul_dense(&DMatrix::zeros(2, 2));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let csr = sample().to_csr();
+        let t = csr.transpose();
+        assert_eq!(
+            t.to_dense::<3, 3>(),
+            Ok(Matrix::from([[4., 0., 3.], [0., 0., 5.], [1., 2., 0.]]))
+        );
+        assert_eq!(sample().to_csc().transpose().to_csr(), t);
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let m = Matrix::from([[0., 1., 0., 0.], [2., 0., 0., 3.]]);
+        let csr = CsrMatrix::from(&m);
+        assert_eq!(csr.nnz(), 3);
+        assert_eq!(csr.to_dense::<2, 4>(), Ok(m.clone()));
+        let csc = CscMatrix::from(&m);
+        assert_eq!(csc.to_dense::<2, 4>(), Ok(m.clone()));
+        assert_eq!(
+            csr.to_dense::<4, 2>(),
+            Err(String::from("Expected 4 rows but got 2"))
+        );
+        assert_eq!(CsrMatrix::from(&DMatrix::from(m)), csr);
+    }
+}
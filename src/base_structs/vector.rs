@@ -20,6 +20,46 @@ impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
     }
 }
 
+impl<T, const N: usize> TryFrom<Vec<T>> for Vector<T, N> {
+    type Error = String;
+    fn try_from(d: Vec<T>) -> Result<Vector<T, N>, String> {
+        if d.len() != N {
+            return Err(format!("Expected {} elements but got {}", N, d.len()));
+        }
+        Ok(Vector { data: d })
+    }
+}
+
+impl<T: Clone, const N: usize> TryFrom<&[T]> for Vector<T, N> {
+    type Error = String;
+    fn try_from(s: &[T]) -> Result<Vector<T, N>, String> {
+        Vector::try_from(s.to_vec())
+    }
+}
+
+impl<T, const N: usize> Vector<T, N> {
+    //builds the vector by calling f(index) for every element
+    pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> Vector<T, N> {
+        Vector {
+            data: (0..N).map(f).collect(),
+        }
+    }
+}
+
+impl<T: Scalar, const N: usize> Vector<T, N> {
+    pub fn zeros() -> Vector<T, N> {
+        Vector::from([T::zero(); N])
+    }
+
+    //standard basis vector: one at index i, zero elsewhere
+    pub fn unit(i: usize) -> Vector<T, N> {
+        if i >= N {
+            panic!("Vector: index out of bound");
+        }
+        Vector::from_fn(|k| if k == i { T::one() } else { T::zero() })
+    }
+}
+
 impl<T: std::fmt::Debug, const N: usize> Vector<T, N> {
     pub fn out(&self) {
         println!("{:?}", self.data);
@@ -178,3 +218,50 @@ impl<T: Scalar, const N: usize> Vector<T, N> {
         Vector { data: res.clone() }
     }
 }
+
+#[cfg(test)]
+mod constructors {
+    use super::*;
+
+    #[test]
+    fn test_zeros() {
+        assert_eq!(Vector::<f32, 3>::zeros(), Vector::from([0., 0., 0.]));
+    }
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(Vector::<f32, 3>::unit(0), Vector::from([1., 0., 0.]));
+        assert_eq!(Vector::<f32, 3>::unit(2), Vector::from([0., 0., 1.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector: index out of bound")]
+    fn test_unit_out_of_bound() {
+        Vector::<f32, 2>::unit(2);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let v = Vector::<f32, 4>::from_fn(|i| (i * i) as f32);
+        assert_eq!(v, Vector::from([0., 1., 4., 9.]));
+    }
+}
+
+#[cfg(test)]
+mod try_from {
+    use super::*;
+
+    #[test]
+    fn test_try_from_vec() {
+        let v = Vector::<f32, 3>::try_from(vec![1., 2., 3.]);
+        assert_eq!(v, Ok(Vector::from([1., 2., 3.])));
+        let v = Vector::<f32, 2>::try_from(&[4., 5.][..]);
+        assert_eq!(v, Ok(Vector::from([4., 5.])));
+    }
+
+    #[test]
+    fn test_try_from_vec_wrong_len() {
+        let v = Vector::<f32, 3>::try_from(vec![1., 2.]);
+        assert_eq!(v, Err(String::from("Expected 3 elements but got 2")));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::num_traits::scalar::Scalar;
+use crate::num_traits::ring::Ring;
 use crate::base_structs::vector::Vector;
 use std::ops::Add;
 
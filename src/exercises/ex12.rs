@@ -1,24 +1,7 @@
-use crate::base_structs::matrix::Matrix;
 use crate::base_structs::matrix::TMatrix;
 use crate::num_traits::scalar::Scalar;
 
 impl<T: Scalar, const M: usize> TMatrix<T, M> {
-    fn _identity() -> [[T; M]; M] {
-        let mut arr = [[T::zero(); M]; M];
-        for i in 0..M {
-            arr[i][i] = T::one();
-        }
-        arr
-    }
-
-    fn _swap_row(mat: &mut [[T; M]; M], x: usize, y: usize) {
-        for i in 0..M {
-            let tmp = mat[y][i];
-            mat[y][i] = mat[x][i];
-            mat[x][i] = tmp;
-        }
-    }
-
     //make a matrix into identity matrix by row operations, then you get the inverse
     //can solve simultaneous equations for example
     //makes a matrix that has opposite effect of the original matrix
@@ -27,65 +10,65 @@ impl<T: Scalar, const M: usize> TMatrix<T, M> {
         if det == T::zero() {
             return Err(String::from("matrix is singular"));
         }
-        let mut res = TMatrix::<T, M>::_identity();
-        let mut d = self.as_arr();
+        let mut res = TMatrix::<T, M>::identity();
+        let mut d = self.clone();
 
         //set the row so that the pivot is different than zero
         for i in 0..M {
-            if d[i][i] == T::zero() {
+            if d.data[i][i] == T::zero() {
                 let mut big = i;
                 for j in 0..M {
                     //compare without sign
-                    if d[j][i] * d[j][i] > d[big][i] * d[big][i] {
+                    if d.data[j][i] * d.data[j][i] > d.data[big][i] * d.data[big][i] {
                         big = j;
                     }
                 }
                 if big == i {
                     return Err(String::from("matrix is singular"));
                 }
-                TMatrix::<T, M>::_swap_row(&mut res, i, big);
-                TMatrix::<T, M>::_swap_row(&mut d, i, big);
+                res.swap_rows(i, big);
+                d.swap_rows(i, big);
             }
         }
 
         //eliminate all numbers under the diagonal element
         for col in 0..M - 1 {
             for row in col + 1..M {
-                let k = d[row][col] / d[col][col];
+                let k = d.data[row][col] / d.data[col][col];
                 for l in 0..M {
-                    d[row][l] = d[row][l] - k * d[col][l];
-                    res[row][l] = res[row][l] - k * res[col][l];
+                    d.data[row][l] = d.data[row][l] - k * d.data[col][l];
+                    res.data[row][l] = res.data[row][l] - k * res.data[col][l];
                 }
-                d[row][col] = T::zero();
+                d.data[row][col] = T::zero();
             }
         }
 
         //scale all the pivots coefficients to 1
         for row in 0..M {
-            let div = d[row][row];
+            let div = d.data[row][row];
             for col in 0..M {
-                d[row][col] = d[row][col] / div;
-                res[row][col] = res[row][col] / div;
+                d.data[row][col] = d.data[row][col] / div;
+                res.data[row][col] = res.data[row][col] / div;
             }
         }
 
         //eliminate all numbers above the diagonal
         for row in 0..M {
             for col in row + 1..M {
-                let k = d[row][col];
+                let k = d.data[row][col];
                 for l in 0..M {
-                    d[row][l] = d[row][l] - d[col][l] * k;
-                    res[row][l] = res[row][l] - res[col][l] * k;
+                    d.data[row][l] = d.data[row][l] - d.data[col][l] * k;
+                    res.data[row][l] = res.data[row][l] - res.data[col][l] * k;
                 }
-                d[row][col] = T::zero();
+                d.data[row][col] = T::zero();
             }
         }
-        Ok(Matrix::from(res))
+        Ok(res)
     }
 }
 #[cfg(test)]
 mod inverse {
-    use super::*;
+    use crate::base_structs::matrix::Matrix;
     use crate::utils::comp::matrices_are_equal;
 
     #[test]
@@ -0,0 +1,46 @@
+use crate::base_structs::vector::{TVector3, Vector};
+use crate::num_traits::scalar::Scalar;
+
+//axis-aligned bounding box, min <= max on every axis
+#[derive(PartialEq, Debug, Clone)]
+pub struct Aabb<T> {
+    pub min: TVector3<T>,
+    pub max: TVector3<T>,
+}
+
+impl<T: Scalar> Aabb<T> {
+    //the corners may be given in any order
+    pub fn new(a: &TVector3<T>, b: &TVector3<T>) -> Aabb<T> {
+        let lo = Vector::from_fn(|i| if a[i] < b[i] { a[i] } else { b[i] });
+        let hi = Vector::from_fn(|i| if a[i] < b[i] { b[i] } else { a[i] });
+        Aabb { min: lo, max: hi }
+    }
+
+    pub fn center(&self) -> TVector3<T> {
+        (self.min.clone() + self.max.clone()) / (T::one() + T::one())
+    }
+
+    pub fn half_extents(&self) -> TVector3<T> {
+        (self.max.clone() - self.min.clone()) / (T::one() + T::one())
+    }
+
+    pub fn contains_point(&self, p: &TVector3<T>) -> bool {
+        (0..3).all(|i| self.min[i] <= p[i] && p[i] <= self.max[i])
+    }
+}
+
+#[cfg(test)]
+mod bounding_box {
+    use super::*;
+
+    #[test]
+    fn test_new_sorts_corners() {
+        let b = Aabb::new(&Vector::from([1., -1., 4.]), &Vector::from([-1., 1., 2.]));
+        assert_eq!(b.min, Vector::from([-1., -1., 2.]));
+        assert_eq!(b.max, Vector::from([1., 1., 4.]));
+        assert_eq!(b.center(), Vector::from([0., 0., 3.]));
+        assert_eq!(b.half_extents(), Vector::from([1., 1., 1.]));
+        assert!(b.contains_point(&Vector::from([0., 1., 2.])));
+        assert!(!b.contains_point(&Vector::from([0., 0., 0.])));
+    }
+}
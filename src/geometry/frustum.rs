@@ -0,0 +1,220 @@
+use crate::base_structs::matrix::TMatrix4;
+use crate::base_structs::vector::{TVector3, Vector};
+use crate::geometry::aabb::Aabb;
+use crate::geometry::plane::Plane;
+use crate::geometry::sphere::Sphere;
+use crate::num_traits::scalar::Scalar;
+use crate::transforms::convention::Convention;
+
+//clip space depth range of the projection the planes are extracted from
+//opengl maps depth to [-1, 1], directx and vulkan to [0, 1]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum DepthRange {
+    NegativeOneToOne,
+    ZeroToOne,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Containment {
+    Outside,
+    Intersecting,
+    Inside,
+}
+
+//planes are normalized and their normals point into the frustum
+#[derive(PartialEq, Debug, Clone)]
+pub struct Frustum<T> {
+    //left, right, bottom, top, near, far
+    pub planes: [Plane<T>; 6],
+}
+
+impl<T: Scalar> Frustum<T> {
+    //gribb-hartmann: a point is inside when -w <= x, y, z <= w in clip space,
+    //each of those inequalities is a plane given by a sum or difference of two rows
+    pub fn from_matrix(m: &TMatrix4<T>, convention: Convention, depth: DepthRange) -> Frustum<T> {
+        let r = convention.apply(m.clone()).as_vec();
+        let row = |j: usize| Vector::from([r[j][0], r[j][1], r[j][2], r[j][3]]);
+        let (x, y, z, w) = (row(0), row(1), row(2), row(3));
+        let near = match depth {
+            DepthRange::NegativeOneToOne => w.clone() + z.clone(),
+            DepthRange::ZeroToOne => z.clone(),
+        };
+        let planes = [
+            w.clone() + x.clone(),
+            w.clone() - x,
+            w.clone() + y.clone(),
+            w.clone() - y,
+            near,
+            w - z,
+        ];
+        Frustum {
+            planes: planes.map(|p| Plane::from_coefficients(&p).normalize()),
+        }
+    }
+
+    pub fn contains_point(&self, p: &TVector3<T>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(p) >= T::zero())
+    }
+
+    pub fn classify_sphere(&self, s: &Sphere<T>) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in self.planes.iter() {
+            let d = plane.signed_distance(&s.center);
+            if d < -s.radius {
+                return Containment::Outside;
+            }
+            if d < s.radius {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    //conservative: a box near a frustum corner can be reported as intersecting
+    //although it is outside, it is never culled wrongly
+    pub fn classify_aabb(&self, b: &Aabb<T>) -> Containment {
+        let (center, half) = (b.center(), b.half_extents());
+        let mut result = Containment::Inside;
+        for plane in self.planes.iter() {
+            let n = &plane.normal;
+            //projection radius of the box onto the plane normal
+            let r = half[0] * n[0].abs() + half[1] * n[1].abs() + half[2] * n[2].abs();
+            let d = plane.signed_distance(&center);
+            if d < -r {
+                return Containment::Outside;
+            }
+            if d < r {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    pub fn intersects_sphere(&self, s: &Sphere<T>) -> bool {
+        self.classify_sphere(s) != Containment::Outside
+    }
+
+    pub fn intersects_aabb(&self, b: &Aabb<T>) -> bool {
+        self.classify_aabb(b) != Containment::Outside
+    }
+}
+
+#[cfg(test)]
+mod culling {
+    use super::*;
+    use crate::base_structs::matrix::Matrix;
+    use crate::transforms::convention::Handedness;
+
+    //opengl style right handed perspective, 90 degree field of view
+    fn perspective(near: f32, far: f32, depth: DepthRange) -> TMatrix4<f32> {
+        let (a, b) = match depth {
+            DepthRange::NegativeOneToOne => {
+                ((far + near) / (near - far), 2. * far * near / (near - far))
+            }
+            DepthRange::ZeroToOne => (far / (near - far), far * near / (near - far)),
+        };
+        Matrix::from([
+            [1., 0., 0., 0.],
+            [0., 1., 0., 0.],
+            [0., 0., a, b],
+            [0., 0., -1., 0.],
+        ])
+    }
+
+    fn view_projection(depth: DepthRange) -> TMatrix4<f32> {
+        //camera at z = 10 looking toward the origin
+        let view = TMatrix4::look_at(
+            &Vector::from([0., 0., 10.]),
+            &Vector::from([0., 0., 0.]),
+            &Vector::from([0., 1., 0.]),
+            Handedness::Right,
+            Convention::ColumnVector,
+        );
+        perspective(1., 100., depth) * view
+    }
+
+    #[test]
+    fn test_planes() {
+        for depth in [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne] {
+            let f = Frustum::from_matrix(
+                &perspective(1., 100., depth),
+                Convention::ColumnVector,
+                depth,
+            );
+            let near = &f.planes[4];
+            let far = &f.planes[5];
+            assert!((near.signed_distance(&Vector::from([0., 0., -1.]))).abs() < 1e-4);
+            assert!((far.signed_distance(&Vector::from([0., 0., -100.]))).abs() < 1e-2);
+            assert!((near.normal[2] + 1.).abs() < 1e-5);
+            assert!((far.normal[2] - 1.).abs() < 1e-5);
+            //the side planes go through the eye at 45 degrees
+            let s = std::f32::consts::FRAC_1_SQRT_2;
+            assert!((f.planes[0].normal[0] - s).abs() < 1e-5);
+            assert!(f.planes[0].d.abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_points() {
+        let depth = DepthRange::NegativeOneToOne;
+        let f = Frustum::from_matrix(&view_projection(depth), Convention::ColumnVector, depth);
+        assert!(f.contains_point(&Vector::from([0., 0., 0.])));
+        assert!(f.contains_point(&Vector::from([4., -4., 5.])));
+        //behind the camera, before the near plane, beyond the far plane, off to the side
+        assert!(!f.contains_point(&Vector::from([0., 0., 11.])));
+        assert!(!f.contains_point(&Vector::from([0., 0., 9.5])));
+        assert!(!f.contains_point(&Vector::from([0., 0., -95.])));
+        assert!(!f.contains_point(&Vector::from([6., 0., 5.])));
+    }
+
+    #[test]
+    fn test_row_vector_convention() {
+        let depth = DepthRange::ZeroToOne;
+        let mut m = view_projection(depth);
+        let col = Frustum::from_matrix(&m, Convention::ColumnVector, depth);
+        let row = Frustum::from_matrix(&m.transpose(), Convention::RowVector, depth);
+        assert_eq!(col, row);
+    }
+
+    #[test]
+    fn test_spheres() {
+        let depth = DepthRange::ZeroToOne;
+        let f = Frustum::from_matrix(&view_projection(depth), Convention::ColumnVector, depth);
+        let s = |c: [f32; 3], r: f32| Sphere::new(Vector::from(c), r);
+        assert_eq!(f.classify_sphere(&s([0., 0., 0.], 1.)), Containment::Inside);
+        assert_eq!(
+            f.classify_sphere(&s([12., 0., 0.], 1.)),
+            Containment::Outside
+        );
+        //the right plane is x = 10 - z, at z = 0 the center is 0.5 / sqrt(2) outside
+        assert_eq!(
+            f.classify_sphere(&s([10.5, 0., 0.], 1.)),
+            Containment::Intersecting
+        );
+        assert!(f.intersects_sphere(&s([0., 0., 12.], 3.5)));
+        assert!(!f.intersects_sphere(&s([0., 0., 12.], 2.)));
+    }
+
+    #[test]
+    fn test_boxes() {
+        let depth = DepthRange::NegativeOneToOne;
+        let f = Frustum::from_matrix(&view_projection(depth), Convention::ColumnVector, depth);
+        let b = |lo: [f32; 3], hi: [f32; 3]| Aabb::new(&Vector::from(lo), &Vector::from(hi));
+        assert_eq!(
+            f.classify_aabb(&b([-1., -1., -1.], [1., 1., 1.])),
+            Containment::Inside
+        );
+        assert_eq!(
+            f.classify_aabb(&b([9., -1., -1.], [11., 1., 1.])),
+            Containment::Intersecting
+        );
+        assert_eq!(
+            f.classify_aabb(&b([12., -1., -1.], [14., 1., 1.])),
+            Containment::Outside
+        );
+        assert!(!f.intersects_aabb(&b([-1., -1., 20.], [1., 1., 30.])));
+        assert!(f.intersects_aabb(&b([-1., -1., -200.], [1., 1., 0.])));
+    }
+}
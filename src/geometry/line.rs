@@ -0,0 +1,191 @@
+use crate::base_structs::vector::TVector3;
+use crate::num_traits::scalar::Scalar;
+
+//infinite line through point along direction, the direction need not be normalized
+#[derive(PartialEq, Debug, Clone)]
+pub struct Line<T> {
+    pub point: TVector3<T>,
+    pub direction: TVector3<T>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Segment<T> {
+    pub start: TVector3<T>,
+    pub end: TVector3<T>,
+}
+
+fn clamp01<T: Scalar>(t: T) -> T {
+    if t < T::zero() {
+        T::zero()
+    } else if t > T::one() {
+        T::one()
+    } else {
+        t
+    }
+}
+
+impl<T: Scalar> Line<T> {
+    pub fn new(point: TVector3<T>, direction: TVector3<T>) -> Line<T> {
+        Line { point, direction }
+    }
+
+    pub fn at(&self, t: T) -> TVector3<T> {
+        self.point.clone() + self.direction.clone() * t
+    }
+
+    pub fn closest_point(&self, p: &TVector3<T>) -> TVector3<T> {
+        let d = &self.direction;
+        let t = (p.clone() - self.point.clone()).dot(d) / d.dot(d);
+        self.at(t)
+    }
+
+    pub fn distance_to_point(&self, p: &TVector3<T>) -> T {
+        (p.clone() - self.closest_point(p)).abs()
+    }
+}
+
+impl<T: Scalar> Segment<T> {
+    pub fn new(start: TVector3<T>, end: TVector3<T>) -> Segment<T> {
+        Segment { start, end }
+    }
+
+    //t = 0 is the start, t = 1 the end
+    pub fn at(&self, t: T) -> TVector3<T> {
+        self.start.clone() + self.direction() * t
+    }
+
+    pub fn direction(&self) -> TVector3<T> {
+        self.end.clone() - self.start.clone()
+    }
+
+    pub fn length(&self) -> T {
+        self.direction().abs()
+    }
+
+    pub fn closest_point(&self, p: &TVector3<T>) -> TVector3<T> {
+        let d = self.direction();
+        let len2 = d.dot(&d);
+        if len2 == T::zero() {
+            return self.start.clone();
+        }
+        self.at(clamp01((p.clone() - self.start.clone()).dot(&d) / len2))
+    }
+
+    //closest pair of points, the first on self and the second on other
+    //minimizes |self.at(s) - other.at(t)| over s, t in [0, 1] (ericson, real-time collision detection 5.1.9)
+    pub fn closest_points(&self, other: &Segment<T>) -> (TVector3<T>, TVector3<T>) {
+        let (d1, d2) = (self.direction(), other.direction());
+        let r = self.start.clone() - other.start.clone();
+        let (a, e, f) = (d1.dot(&d1), d2.dot(&d2), d2.dot(&r));
+        let z = T::zero();
+        //degenerate segments are points
+        if a == z && e == z {
+            return (self.start.clone(), other.start.clone());
+        }
+        if a == z {
+            return (self.start.clone(), other.at(clamp01(f / e)));
+        }
+        let c = d1.dot(&r);
+        if e == z {
+            return (self.at(clamp01(-c / a)), other.start.clone());
+        }
+        let b = d1.dot(&d2);
+        let denom = a * e - b * b;
+        //parallel segments have no unique pair, any s works so start from 0
+        let mut s = if denom > z {
+            clamp01((b * f - c * e) / denom)
+        } else {
+            z
+        };
+        let mut t = (b * s + f) / e;
+        //t outside the other segment: clamp it and recompute s for the clamped t
+        if t < z {
+            t = z;
+            s = clamp01(-c / a);
+        } else if t > T::one() {
+            t = T::one();
+            s = clamp01((b - c) / a);
+        }
+        (self.at(s), other.at(t))
+    }
+
+    pub fn distance_to_segment(&self, other: &Segment<T>) -> T {
+        let (p, q) = self.closest_points(other);
+        (p - q).abs()
+    }
+}
+
+#[cfg(test)]
+mod closest_points {
+    use super::*;
+    use crate::base_structs::vector::Vector;
+    use crate::utils::comp::{floats_are_equal, vectors_are_equal};
+
+    fn seg(a: [f32; 3], b: [f32; 3]) -> Segment<f32> {
+        Segment::new(Vector::from(a), Vector::from(b))
+    }
+
+    #[test]
+    fn test_line_closest_point() {
+        let l = Line::new(Vector::from([1., 1., 0.]), Vector::from([2., 0., 0.]));
+        let p = Vector::from([5., 4., 0.]);
+        assert_eq!(l.closest_point(&p), Vector::from([5., 1., 0.]));
+        assert_eq!(l.distance_to_point(&p), 3.);
+    }
+
+    #[test]
+    fn test_segment_closest_point() {
+        let s = seg([0., 0., 0.], [2., 0., 0.]);
+        assert_eq!(s.length(), 2.);
+        assert_eq!(
+            s.closest_point(&Vector::from([1., 3., 0.])),
+            Vector::from([1., 0., 0.])
+        );
+        assert_eq!(
+            s.closest_point(&Vector::from([-4., 1., 0.])),
+            Vector::from([0., 0., 0.])
+        );
+        assert_eq!(
+            s.closest_point(&Vector::from([7., 0., 1.])),
+            Vector::from([2., 0., 0.])
+        );
+    }
+
+    #[test]
+    fn test_crossing_segments() {
+        //skew segments crossing above each other
+        let a = seg([-1., 0., 0.], [1., 0., 0.]);
+        let b = seg([0., -1., 2.], [0., 1., 2.]);
+        let (p, q) = a.closest_points(&b);
+        assert!(vectors_are_equal(&p, &Vector::from([0., 0., 0.])));
+        assert!(vectors_are_equal(&q, &Vector::from([0., 0., 2.])));
+        assert!(floats_are_equal(a.distance_to_segment(&b), 2.));
+    }
+
+    #[test]
+    fn test_clamped_segments() {
+        //the infinite lines meet at the origin but the segments stop short of it
+        let a = seg([1., 0., 0.], [3., 0., 0.]);
+        let b = seg([0., 2., 0.], [0., 5., 0.]);
+        let (p, q) = a.closest_points(&b);
+        assert_eq!(p, Vector::from([1., 0., 0.]));
+        assert_eq!(q, Vector::from([0., 2., 0.]));
+        let (q, p) = b.closest_points(&a);
+        assert_eq!(p, Vector::from([1., 0., 0.]));
+        assert_eq!(q, Vector::from([0., 2., 0.]));
+    }
+
+    #[test]
+    fn test_parallel_and_degenerate_segments() {
+        let a = seg([0., 0., 0.], [2., 0., 0.]);
+        let b = seg([1., 1., 0.], [4., 1., 0.]);
+        assert!(floats_are_equal(a.distance_to_segment(&b), 1.));
+        let point = seg([1., 3., 0.], [1., 3., 0.]);
+        let (p, q) = a.closest_points(&point);
+        assert_eq!(p, Vector::from([1., 0., 0.]));
+        assert_eq!(q, Vector::from([1., 3., 0.]));
+        let (q, p) = point.closest_points(&a);
+        assert_eq!(p, Vector::from([1., 0., 0.]));
+        assert_eq!(q, Vector::from([1., 3., 0.]));
+    }
+}
@@ -0,0 +1,7 @@
+pub mod aabb;
+pub mod frustum;
+pub mod line;
+pub mod plane;
+pub mod ray;
+pub mod sphere;
+pub mod triangle;
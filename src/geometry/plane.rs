@@ -0,0 +1,91 @@
+use crate::base_structs::vector::{TVector3, TVector4, Vector};
+use crate::exercises::ex06::cross_product;
+use crate::num_traits::scalar::Scalar;
+
+//all points p with normal . p + d = 0
+#[derive(PartialEq, Debug, Clone)]
+pub struct Plane<T> {
+    pub normal: TVector3<T>,
+    pub d: T,
+}
+
+impl<T: Scalar> Plane<T> {
+    pub fn new(normal: TVector3<T>, d: T) -> Plane<T> {
+        Plane { normal, d }
+    }
+
+    pub fn from_point_normal(point: &TVector3<T>, normal: &TVector3<T>) -> Plane<T> {
+        Plane::new(normal.clone(), -normal.dot(point))
+    }
+
+    //normal follows the winding a, b, c counter-clockwise
+    pub fn from_points(a: &TVector3<T>, b: &TVector3<T>, c: &TVector3<T>) -> Plane<T> {
+        let n = cross_product(&(b.clone() - a.clone()), &(c.clone() - a.clone()));
+        Plane::from_point_normal(a, &n)
+    }
+
+    //(a, b, c, d) of ax + by + cz + d = 0
+    pub fn from_coefficients(v: &TVector4<T>) -> Plane<T> {
+        Plane::new(Vector::from([v[0], v[1], v[2]]), v[3])
+    }
+
+    //unit normal, so signed_distance returns actual distances
+    pub fn normalize(&self) -> Plane<T> {
+        let len = self.normal.abs();
+        Plane::new(self.normal.clone() / len, self.d / len)
+    }
+
+    //positive on the side the normal points to, scaled by |normal| if not normalized
+    pub fn signed_distance(&self, p: &TVector3<T>) -> T {
+        self.normal.dot(p) + self.d
+    }
+
+    pub fn distance(&self, p: &TVector3<T>) -> T {
+        (self.signed_distance(p) / self.normal.abs()).abs()
+    }
+
+    //closest point on the plane
+    pub fn project_point(&self, p: &TVector3<T>) -> TVector3<T> {
+        let n = &self.normal;
+        p.clone() - n.clone() * (self.signed_distance(p) / n.dot(n))
+    }
+}
+
+#[cfg(test)]
+mod planes {
+    use super::*;
+
+    #[test]
+    fn test_signed_distance() {
+        let p = Plane::from_point_normal(&Vector::from([0., 0., 2.]), &Vector::from([0., 0., 1.]));
+        assert_eq!(p.d, -2.);
+        assert_eq!(p.signed_distance(&Vector::from([5., 5., 5.])), 3.);
+        assert_eq!(p.signed_distance(&Vector::from([5., 5., 0.])), -2.);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let p = Plane::from_coefficients(&Vector::from([0., 3., 4., 10.])).normalize();
+        assert_eq!(p.normal, Vector::from([0., 0.6, 0.8]));
+        assert_eq!(p.d, 2.);
+        assert_eq!(p.signed_distance(&Vector::from([0., 0., 0.])), 2.);
+    }
+
+    #[test]
+    fn test_distance_and_projection() {
+        let p = Plane::from_points(
+            &Vector::from([0., 0., 3.]),
+            &Vector::from([1., 0., 3.]),
+            &Vector::from([0., 1., 3.]),
+        );
+        assert_eq!(p.normal, Vector::from([0., 0., 1.]));
+        let q = Vector::from([2., -1., -1.]);
+        assert_eq!(p.signed_distance(&q), -4.);
+        assert_eq!(p.distance(&q), 4.);
+        assert_eq!(p.project_point(&q), Vector::from([2., -1., 3.]));
+        //distance does not depend on the length of the normal
+        let scaled = Plane::new(Vector::from([0., 0., 2.]), -6.);
+        assert_eq!(scaled.distance(&q), 4.);
+        assert_eq!(scaled.project_point(&q), Vector::from([2., -1., 3.]));
+    }
+}
@@ -0,0 +1,172 @@
+use crate::base_structs::vector::TVector3;
+use crate::exercises::ex06::cross_product;
+use crate::geometry::aabb::Aabb;
+use crate::geometry::plane::Plane;
+use crate::geometry::triangle::Triangle;
+use crate::num_traits::scalar::Scalar;
+
+//half line origin + t * direction with t >= 0
+//intersections are returned as values of t, in units of |direction|
+#[derive(PartialEq, Debug, Clone)]
+pub struct Ray<T> {
+    pub origin: TVector3<T>,
+    pub direction: TVector3<T>,
+}
+
+impl<T: Scalar> Ray<T> {
+    pub fn new(origin: TVector3<T>, direction: TVector3<T>) -> Ray<T> {
+        Ray { origin, direction }
+    }
+
+    pub fn at(&self, t: T) -> TVector3<T> {
+        self.origin.clone() + self.direction.clone() * t
+    }
+
+    //None when parallel to the plane or when the plane is behind the origin
+    pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<T> {
+        let denom = plane.normal.dot(&self.direction);
+        if denom == T::zero() {
+            return None;
+        }
+        let t = -plane.signed_distance(&self.origin) / denom;
+        if t < T::zero() {
+            None
+        } else {
+            Some(t)
+        }
+    }
+
+    //moller-trumbore, hits from both sides of the triangle
+    pub fn intersect_triangle(&self, tri: &Triangle<T>) -> Option<T> {
+        let e1 = tri.b.clone() - tri.a.clone();
+        let e2 = tri.c.clone() - tri.a.clone();
+        let p = cross_product(&self.direction, &e2);
+        let det = e1.dot(&p);
+        //the ray is parallel to the triangle's plane
+        if det.abs() <= T::epsilon() * e1.abs() * p.abs() {
+            return None;
+        }
+        let inv = T::one() / det;
+        let s = self.origin.clone() - tri.a.clone();
+        let u = s.dot(&p) * inv;
+        if u < T::zero() || u > T::one() {
+            return None;
+        }
+        let q = cross_product(&s, &e1);
+        let v = self.direction.dot(&q) * inv;
+        if v < T::zero() || u + v > T::one() {
+            return None;
+        }
+        let t = e2.dot(&q) * inv;
+        if t < T::zero() {
+            None
+        } else {
+            Some(t)
+        }
+    }
+
+    //slab test, the range of t spent inside the box, entry is 0 if the origin is inside
+    pub fn intersect_aabb(&self, b: &Aabb<T>) -> Option<(T, T)> {
+        let mut entry = T::zero();
+        let mut exit: Option<T> = None;
+        for i in 0..3 {
+            let (o, d) = (self.origin[i], self.direction[i]);
+            if d == T::zero() {
+                //parallel to this slab, it either always or never overlaps
+                if o < b.min[i] || o > b.max[i] {
+                    return None;
+                }
+                continue;
+            }
+            let (mut t0, mut t1) = ((b.min[i] - o) / d, (b.max[i] - o) / d);
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+            if t0 > entry {
+                entry = t0;
+            }
+            exit = match exit {
+                Some(e) if e < t1 => Some(e),
+                _ => Some(t1),
+            };
+        }
+        match exit {
+            Some(e) if e < entry => None,
+            Some(e) => Some((entry, e)),
+            //zero direction, the origin is inside the box
+            None => Some((T::zero(), T::zero())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod intersections {
+    use super::*;
+    use crate::base_structs::vector::Vector;
+
+    fn ray(o: [f32; 3], d: [f32; 3]) -> Ray<f32> {
+        Ray::new(Vector::from(o), Vector::from(d))
+    }
+
+    #[test]
+    fn test_plane() {
+        let p = Plane::new(Vector::from([0., 0., 1.]), -2.);
+        let r = ray([1., 1., 0.], [0., 0., 0.5]);
+        assert_eq!(r.intersect_plane(&p), Some(4.));
+        assert_eq!(r.at(4.), Vector::from([1., 1., 2.]));
+        assert_eq!(ray([0., 0., 3.], [0., 0., 1.]).intersect_plane(&p), None);
+        assert_eq!(ray([0., 0., 0.], [1., 0., 0.]).intersect_plane(&p), None);
+    }
+
+    #[test]
+    fn test_triangle() {
+        let t = Triangle::new(
+            Vector::from([0., 0., 0.]),
+            Vector::from([2., 0., 0.]),
+            Vector::from([0., 2., 0.]),
+        );
+        assert_eq!(
+            ray([0.5, 0.5, 3.], [0., 0., -1.]).intersect_triangle(&t),
+            Some(3.)
+        );
+        //from below, the back side
+        assert_eq!(
+            ray([0.5, 0.5, -1.], [0., 0., 2.]).intersect_triangle(&t),
+            Some(0.5)
+        );
+        //misses, past the hypotenuse, pointing away, parallel
+        assert_eq!(
+            ray([1.5, 1.5, 3.], [0., 0., -1.]).intersect_triangle(&t),
+            None
+        );
+        assert_eq!(
+            ray([0.5, 0.5, 3.], [0., 0., 1.]).intersect_triangle(&t),
+            None
+        );
+        assert_eq!(
+            ray([0.5, 0.5, 3.], [1., 0., 0.]).intersect_triangle(&t),
+            None
+        );
+    }
+
+    #[test]
+    fn test_aabb() {
+        let b = Aabb::new(&Vector::from([-1., -1., -1.]), &Vector::from([1., 1., 1.]));
+        assert_eq!(
+            ray([-5., 0., 0.], [1., 0., 0.]).intersect_aabb(&b),
+            Some((4., 6.))
+        );
+        assert_eq!(
+            ray([0., 0., 0.], [0., 2., 0.]).intersect_aabb(&b),
+            Some((0., 0.5))
+        );
+        assert_eq!(ray([-5., 0., 0.], [-1., 0., 0.]).intersect_aabb(&b), None);
+        assert_eq!(ray([-5., 3., 0.], [1., 0., 0.]).intersect_aabb(&b), None);
+        //diagonal through a corner region that misses
+        assert_eq!(ray([-3., 0., 0.], [1., 2., 0.]).intersect_aabb(&b), None);
+        assert_eq!(
+            ray([-2., -2., 0.], [1., 1., 0.]).intersect_aabb(&b),
+            Some((1., 3.))
+        );
+    }
+}
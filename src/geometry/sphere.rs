@@ -0,0 +1,90 @@
+use crate::base_structs::vector::TVector3;
+use crate::geometry::aabb::Aabb;
+use crate::num_traits::scalar::Scalar;
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Sphere<T> {
+    pub center: TVector3<T>,
+    pub radius: T,
+}
+
+impl<T: Scalar> Sphere<T> {
+    pub fn new(center: TVector3<T>, radius: T) -> Sphere<T> {
+        Sphere { center, radius }
+    }
+
+    pub fn contains_point(&self, p: &TVector3<T>) -> bool {
+        let d = p.clone() - self.center.clone();
+        d.dot(&d) <= self.radius * self.radius
+    }
+
+    //touching counts as intersecting
+    pub fn intersects_sphere(&self, other: &Sphere<T>) -> bool {
+        let d = other.center.clone() - self.center.clone();
+        let r = self.radius + other.radius;
+        d.dot(&d) <= r * r
+    }
+
+    //distance from the center to the closest point of the box
+    pub fn intersects_aabb(&self, b: &Aabb<T>) -> bool {
+        let mut dist = T::zero();
+        for i in 0..3 {
+            let c = self.center[i];
+            let gap = if c < b.min[i] {
+                b.min[i] - c
+            } else if c > b.max[i] {
+                c - b.max[i]
+            } else {
+                T::zero()
+            };
+            dist = dist + gap * gap;
+        }
+        dist <= self.radius * self.radius
+    }
+}
+
+#[cfg(test)]
+mod bounding_sphere {
+    use super::*;
+    use crate::base_structs::vector::Vector;
+
+    fn unit() -> Sphere<f32> {
+        Sphere::new(Vector::from([1., 2., 3.]), 2.)
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let s = unit();
+        assert!(s.contains_point(&Vector::from([1., 2., 3.])));
+        //on the surface
+        assert!(s.contains_point(&Vector::from([1., 4., 3.])));
+        assert!(!s.contains_point(&Vector::from([1., 2., 5.1])));
+        assert!(!s.contains_point(&Vector::from([2.5, 3.5, 3.])));
+    }
+
+    #[test]
+    fn test_intersects_sphere() {
+        let s = unit();
+        assert!(s.intersects_sphere(&s));
+        //touching at (1, 2, 5)
+        assert!(s.intersects_sphere(&Sphere::new(Vector::from([1., 2., 6.]), 1.)));
+        assert!(!s.intersects_sphere(&Sphere::new(Vector::from([1., 2., 6.1]), 1.)));
+        //one inside the other
+        assert!(s.intersects_sphere(&Sphere::new(Vector::from([1., 2., 3.5]), 0.1)));
+    }
+
+    #[test]
+    fn test_intersects_aabb() {
+        let s = unit();
+        let b = |lo: [f32; 3], hi: [f32; 3]| Aabb::new(&Vector::from(lo), &Vector::from(hi));
+        //center inside the box
+        assert!(s.intersects_aabb(&b([0., 0., 0.], [5., 5., 5.])));
+        //box face at distance 2
+        assert!(s.intersects_aabb(&b([3., 0., 0.], [4., 5., 5.])));
+        assert!(!s.intersects_aabb(&b([3.1, 0., 0.], [4., 5., 5.])));
+        //nearest to a corner at distance sqrt(3)
+        assert!(s.intersects_aabb(&b([2., 3., 4.], [3., 4., 5.])));
+        //corner at distance sqrt(12)
+        assert!(!s.intersects_aabb(&b([3., 4., 5.], [4., 5., 6.])));
+    }
+}
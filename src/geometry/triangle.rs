@@ -0,0 +1,87 @@
+use crate::base_structs::vector::{TVector3, Vector};
+use crate::exercises::ex06::cross_product;
+use crate::num_traits::scalar::Scalar;
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Triangle<T> {
+    pub a: TVector3<T>,
+    pub b: TVector3<T>,
+    pub c: TVector3<T>,
+}
+
+impl<T: Scalar> Triangle<T> {
+    pub fn new(a: TVector3<T>, b: TVector3<T>, c: TVector3<T>) -> Triangle<T> {
+        Triangle { a, b, c }
+    }
+
+    //not normalized, its length is twice the area
+    //points toward the side from which a, b, c is counter-clockwise
+    pub fn normal(&self) -> TVector3<T> {
+        cross_product(
+            &(self.b.clone() - self.a.clone()),
+            &(self.c.clone() - self.a.clone()),
+        )
+    }
+
+    pub fn area(&self) -> T {
+        self.normal().abs() / (T::one() + T::one())
+    }
+
+    //weights (u, v, w) with p = u a + v b + w c for p projected onto the triangle's plane
+    //all three are in [0, 1] exactly when p is inside, None for a degenerate triangle
+    pub fn barycentric(&self, p: &TVector3<T>) -> Option<TVector3<T>> {
+        let e0 = self.b.clone() - self.a.clone();
+        let e1 = self.c.clone() - self.a.clone();
+        let e2 = p.clone() - self.a.clone();
+        let (d00, d01, d11) = (e0.dot(&e0), e0.dot(&e1), e1.dot(&e1));
+        let (d20, d21) = (e2.dot(&e0), e2.dot(&e1));
+        let denom = d00 * d11 - d01 * d01;
+        if denom == T::zero() {
+            return None;
+        }
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        Some(Vector::from([T::one() - v - w, v, w]))
+    }
+
+    pub fn from_barycentric(&self, uvw: &TVector3<T>) -> TVector3<T> {
+        self.a.clone() * uvw[0] + self.b.clone() * uvw[1] + self.c.clone() * uvw[2]
+    }
+}
+
+#[cfg(test)]
+mod barycentric {
+    use super::*;
+    use crate::utils::comp::vectors_are_equal;
+
+    fn tri() -> Triangle<f32> {
+        Triangle::new(
+            Vector::from([0., 0., 0.]),
+            Vector::from([4., 0., 0.]),
+            Vector::from([0., 4., 0.]),
+        )
+    }
+
+    #[test]
+    fn test_normal_and_area() {
+        assert_eq!(tri().normal(), Vector::from([0., 0., 16.]));
+        assert_eq!(tri().area(), 8.);
+    }
+
+    #[test]
+    fn test_barycentric() {
+        let t = tri();
+        assert_eq!(t.barycentric(&t.a).unwrap(), Vector::from([1., 0., 0.]));
+        let uvw = t.barycentric(&Vector::from([1., 2., 5.])).unwrap();
+        assert_eq!(uvw, Vector::from([0.25, 0.25, 0.5]));
+        assert!(vectors_are_equal(
+            &t.from_barycentric(&uvw),
+            &Vector::from([1., 2., 0.])
+        ));
+        //outside the triangle one of the weights is negative
+        let uvw = t.barycentric(&Vector::from([4., 4., 0.])).unwrap();
+        assert_eq!(uvw, Vector::from([-1., 1., 1.]));
+        let flat = Triangle::new(t.a.clone(), t.b.clone(), Vector::from([2., 0., 0.]));
+        assert_eq!(flat.barycentric(&t.c), None);
+    }
+}
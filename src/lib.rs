@@ -1,4 +1,8 @@
 pub mod base_structs;
 pub mod exercises;
+pub mod geometry;
+pub mod linalg;
 pub mod num_traits;
+pub mod solvers;
+pub mod transforms;
 pub mod utils;
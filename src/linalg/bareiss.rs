@@ -0,0 +1,203 @@
+use crate::base_structs::dmatrix::DMatrix;
+use crate::base_structs::matrix::{Matrix, TMatrix};
+use crate::num_traits::integer::Integer;
+
+fn overflow() -> String {
+    String::from("integer overflow")
+}
+
+//one fraction-free elimination step on the rows below `row`, pivot at (row, col)
+//(a_ij a_rc - a_ic a_rj) / prev is exact: every entry is a minor of the original matrix
+fn eliminate<T: Integer>(d: &mut [Vec<T>], row: usize, col: usize, prev: &T) -> Result<(), String> {
+    let (top, rest) = d.split_at_mut(row + 1);
+    let pivot = &top[row];
+    for r in rest.iter_mut() {
+        for j in col + 1..pivot.len() {
+            let a = r[j].checked_mul(&pivot[col]).ok_or_else(overflow)?;
+            let b = r[col].checked_mul(&pivot[j]).ok_or_else(overflow)?;
+            let num = a.checked_sub(&b).ok_or_else(overflow)?;
+            r[j] = num.checked_div(prev).ok_or_else(overflow)?;
+        }
+        r[col] = T::zero();
+    }
+    Ok(())
+}
+
+fn pivot<T: Integer>(d: &[Vec<T>], from: usize, col: usize) -> Option<usize> {
+    (from..d.len()).find(|r| !d[*r][col].is_zero())
+}
+
+//bareiss elimination, exact in integers with O(n^3) operations
+//the stored entries are minors, but each one is computed from a product of two minors
+//before the exact division, so fixed width T can overflow even when the determinant
+//fits, that gives Err; num::BigInt never overflows
+fn determinant<T: Integer>(mut d: Vec<Vec<T>>) -> Result<T, String> {
+    let mut prev = T::one();
+    let mut negative = false;
+    for k in 0..d.len() {
+        let p = match pivot(&d, k, k) {
+            Some(p) => p,
+            None => return Ok(T::zero()),
+        };
+        if p != k {
+            d.swap(p, k);
+            negative = !negative;
+        }
+        eliminate(&mut d, k, k, &prev)?;
+        prev = d[k][k].clone();
+    }
+    if negative {
+        T::zero().checked_sub(&prev).ok_or_else(overflow)
+    } else {
+        Ok(prev)
+    }
+}
+
+fn rank<T: Integer>(mut d: Vec<Vec<T>>, cols: usize) -> Result<usize, String> {
+    let mut prev = T::one();
+    let mut row = 0;
+    for col in 0..cols {
+        if row == d.len() {
+            break;
+        }
+        let p = match pivot(&d, row, col) {
+            Some(p) => p,
+            None => continue,
+        };
+        d.swap(p, row);
+        eliminate(&mut d, row, col, &prev)?;
+        prev = d[row][col].clone();
+        row += 1;
+    }
+    Ok(row)
+}
+
+impl<T: Integer, const M: usize> TMatrix<T, M> {
+    pub fn determinant_bareiss(&self) -> Result<T, String> {
+        determinant(self.data.clone())
+    }
+}
+
+impl<T: Integer, const M: usize, const N: usize> Matrix<T, M, N> {
+    pub fn rank_bareiss(&self) -> Result<usize, String> {
+        rank(self.data.clone(), N)
+    }
+}
+
+//with arbitrary precision integers like num::BigInt these never overflow
+impl<T: Integer> DMatrix<T> {
+    pub fn determinant_bareiss(&self) -> Result<T, String> {
+        if !self.is_square() {
+            return Err(String::from("matrix is not square"));
+        }
+        determinant(self.as_vec())
+    }
+
+    pub fn rank_bareiss(&self) -> Result<usize, String> {
+        rank(self.as_vec(), self.ncols())
+    }
+}
+
+#[cfg(test)]
+mod fraction_free {
+    use super::*;
+    use num::BigInt;
+
+    #[test]
+    fn test_determinant() {
+        let m: TMatrix<i64, 3> = Matrix::from([[2, -1, 0], [1, 3, 4], [0, 5, -2]]);
+        assert_eq!(m.determinant_bareiss(), Ok(-54));
+        //needs a row swap
+        let m: TMatrix<i64, 3> = Matrix::from([[0, 1, 2], [3, 4, 5], [6, 7, 9]]);
+        assert_eq!(m.determinant_bareiss(), Ok(-3));
+        let m: TMatrix<i64, 2> = Matrix::from([[1, 2], [2, 4]]);
+        assert_eq!(m.determinant_bareiss(), Ok(0));
+        let id: TMatrix<i128, 4> = TMatrix::identity();
+        assert_eq!(id.determinant_bareiss(), Ok(1));
+    }
+
+    #[test]
+    fn test_matches_float_determinant() {
+        let rows = [[3, 1, -4, 1], [5, 9, 2, -6], [5, 3, 5, -8], [9, 7, 9, 3]];
+        let m: TMatrix<i64, 4> = Matrix::from(rows);
+        let mut f: TMatrix<f32, 4> = Matrix::from_fn(|r, c| rows[r][c] as f32);
+        assert_eq!(m.determinant_bareiss().unwrap() as f32, f.determinant());
+    }
+
+    #[test]
+    fn test_vandermonde() {
+        //vandermonde determinant is the product of the differences, 1 * 2 * 3 * 1 * 2 * 1
+        let m: TMatrix<i64, 4> = Matrix::from_fn(|r, c| (r as i64 + 1).pow(c as u32));
+        assert_eq!(m.determinant_bareiss(), Ok(12));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let big = i64::MAX / 2;
+        let m: TMatrix<i64, 2> = Matrix::from([[big, 1], [3, big]]);
+        assert_eq!(
+            m.determinant_bareiss(),
+            Err(String::from("integer overflow"))
+        );
+        //the same matrix fits in i128
+        let m: TMatrix<i128, 2> = Matrix::from([[big as i128, 1], [3, big as i128]]);
+        assert_eq!(m.determinant_bareiss(), Ok(big as i128 * big as i128 - 3));
+    }
+
+    #[test]
+    fn test_rank() {
+        let m: Matrix<i64, 3, 4> = Matrix::from([[1, 2, 3, 4], [2, 4, 6, 8], [0, 0, 1, 1]]);
+        assert_eq!(m.rank_bareiss(), Ok(2));
+        let m: Matrix<i64, 3, 2> = Matrix::from([[0, 1], [0, 2], [0, 0]]);
+        assert_eq!(m.rank_bareiss(), Ok(1));
+        let m: TMatrix<i64, 3> = Matrix::from([[2, -1, 0], [1, 3, 4], [0, 5, -2]]);
+        assert_eq!(m.rank_bareiss(), Ok(3));
+        let z: Matrix<i64, 2, 3> = Matrix::zeros();
+        assert_eq!(z.rank_bareiss(), Ok(0));
+    }
+
+    #[test]
+    fn test_big_integers() {
+        //the i64 overflow case from above, exactly
+        let big = BigInt::from(i64::MAX / 2);
+        let m = DMatrix::try_from(vec![
+            vec![big.clone(), BigInt::from(1)],
+            vec![BigInt::from(3), big.clone()],
+        ])
+        .unwrap();
+        assert_eq!(m.determinant_bareiss(), Ok(&big * &big - 3));
+        //21! on the diagonal of a triangular matrix is past i64::MAX
+        let n = 21;
+        let m = DMatrix::from_fn(n, n, |r, c| {
+            if r == c {
+                BigInt::from(r as i64 + 1)
+            } else if c > r {
+                BigInt::from(7)
+            } else {
+                BigInt::from(0)
+            }
+        });
+        let fact = (1..=n as i64).fold(BigInt::from(1), |acc, k| acc * k);
+        assert_eq!(m.determinant_bareiss(), Ok(fact));
+        assert_eq!(m.rank_bareiss(), Ok(n));
+        let r = DMatrix::try_from(vec![
+            vec![BigInt::from(1), BigInt::from(2), BigInt::from(3)],
+            vec![BigInt::from(2), BigInt::from(4), BigInt::from(6)],
+        ])
+        .unwrap();
+        assert_eq!(r.rank_bareiss(), Ok(1));
+        assert_eq!(
+            r.determinant_bareiss(),
+            Err(String::from("matrix is not square"))
+        );
+    }
+
+    #[test]
+    fn test_dynamic_matches_fixed() {
+        let rows = [[3, 1, -4, 1], [5, 9, 2, -6], [5, 3, 5, -8], [9, 7, 9, 3]];
+        let m: TMatrix<i64, 4> = Matrix::from(rows);
+        let d = DMatrix::from(m.clone());
+        assert_eq!(d.determinant_bareiss(), m.determinant_bareiss());
+        assert_eq!(d.rank_bareiss(), m.rank_bareiss());
+    }
+}
@@ -0,0 +1,257 @@
+use crate::base_structs::matrix::{Matrix, TMatrix};
+use crate::num_traits::ring::Ring;
+
+//sizes that depend on M and N (M - 1, M + P, ...) can't be written on stable rust,
+//those functions take the output size as a parameter and check it
+impl<T: Ring, const M: usize, const N: usize> Matrix<T, M, N> {
+    //the entries at the given rows and columns, in the given order
+    pub fn submatrix<const R: usize, const C: usize>(
+        &self,
+        rows: &[usize; R],
+        cols: &[usize; C],
+    ) -> Matrix<T, R, C> {
+        if rows.iter().any(|r| *r >= M) || cols.iter().any(|c| *c >= N) {
+            panic!("Matrix: index out of bound");
+        }
+        let d = self.as_vec();
+        Matrix::from_fn(|r, c| d[rows[r]][cols[c]])
+    }
+
+    //contiguous R x C block with its top left corner at (row, col)
+    pub fn fixed_view<const R: usize, const C: usize>(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> Matrix<T, R, C> {
+        if row + R > M || col + C > N {
+            panic!("Matrix: block out of bound");
+        }
+        let d = self.as_vec();
+        Matrix::from_fn(|r, c| d[row + r][col + c])
+    }
+
+    pub fn set_fixed_view<const R: usize, const C: usize>(
+        &mut self,
+        row: usize,
+        col: usize,
+        block: &Matrix<T, R, C>,
+    ) {
+        if row + R > M || col + C > N {
+            panic!("Matrix: block out of bound");
+        }
+        for (r, src) in block.as_vec().into_iter().enumerate() {
+            for (c, item) in src.into_iter().enumerate() {
+                self.data[row + r][col + c] = item;
+            }
+        }
+    }
+
+    pub fn remove_row<const K: usize>(&self, i: usize) -> Result<Matrix<T, K, N>, String> {
+        if K + 1 != M {
+            return Err(format!("Expected K + 1 == {} rows but K is {}", M, K));
+        }
+        if i >= M {
+            return Err(String::from("Matrix: index out of bound"));
+        }
+        let d = self.as_vec();
+        Ok(Matrix::from_fn(|r, c| d[if r < i { r } else { r + 1 }][c]))
+    }
+
+    pub fn remove_col<const K: usize>(&self, j: usize) -> Result<Matrix<T, M, K>, String> {
+        if K + 1 != N {
+            return Err(format!("Expected K + 1 == {} columns but K is {}", N, K));
+        }
+        if j >= N {
+            return Err(String::from("Matrix: index out of bound"));
+        }
+        let d = self.as_vec();
+        Ok(Matrix::from_fn(|r, c| d[r][if c < j { c } else { c + 1 }]))
+    }
+
+    //[self other], side by side
+    pub fn hstack<const P: usize, const S: usize>(
+        &self,
+        other: &Matrix<T, M, P>,
+    ) -> Result<Matrix<T, M, S>, String> {
+        if S != N + P {
+            return Err(format!("Expected {} columns but got {}", N + P, S));
+        }
+        let (a, b) = (self.as_vec(), other.as_vec());
+        Ok(Matrix::from_fn(
+            |r, c| if c < N { a[r][c] } else { b[r][c - N] },
+        ))
+    }
+
+    //self on top of other
+    pub fn vstack<const P: usize, const R: usize>(
+        &self,
+        other: &Matrix<T, P, N>,
+    ) -> Result<Matrix<T, R, N>, String> {
+        if R != M + P {
+            return Err(format!("Expected {} rows but got {}", M + P, R));
+        }
+        let (a, b) = (self.as_vec(), other.as_vec());
+        Ok(Matrix::from_fn(
+            |r, c| if r < M { a[r][c] } else { b[r - M][c] },
+        ))
+    }
+}
+
+impl<T: Ring, const M: usize> TMatrix<T, M> {
+    //determinant of the matrix without row i and column j
+    //cofactor expansion never divides, so integer minors are exact
+    pub fn minor(&self, i: usize, j: usize) -> T {
+        if i >= M || j >= M {
+            panic!("Matrix: index out of bound");
+        }
+        //the empty determinant
+        if M == 1 {
+            return T::one();
+        }
+        let d: Vec<Vec<T>> = (0..M)
+            .filter(|r| *r != i)
+            .map(|r| {
+                (0..M)
+                    .filter(|c| *c != j)
+                    .map(|c| self.data[r][c])
+                    .collect()
+            })
+            .collect();
+        self._deter(d, M - 1)
+    }
+
+    pub fn cofactor(&self, i: usize, j: usize) -> T {
+        let m = self.minor(i, j);
+        if (i + j).is_multiple_of(2) {
+            m
+        } else {
+            -m
+        }
+    }
+
+    pub fn cofactor_matrix(&self) -> TMatrix<T, M> {
+        Matrix::from_fn(|i, j| self.cofactor(i, j))
+    }
+
+    //transpose of the cofactor matrix, A adj(A) = det(A) I even when A is singular
+    pub fn adjugate(&self) -> TMatrix<T, M> {
+        Matrix::from_fn(|i, j| self.cofactor(j, i))
+    }
+}
+
+#[cfg(test)]
+mod block_views {
+    use super::*;
+    use crate::base_structs::vector::Vector;
+    use crate::utils::comp::{floats_are_equal, matrices_are_equal};
+
+    fn sample() -> Matrix<f32, 3, 4> {
+        Matrix::from([[1., 2., 3., 4.], [5., 6., 7., 8.], [9., 10., 11., 12.]])
+    }
+
+    #[test]
+    fn test_submatrix_and_views() {
+        let m = sample();
+        assert_eq!(
+            m.submatrix(&[2, 0], &[1, 3]),
+            Matrix::from([[10., 12.], [2., 4.]])
+        );
+        assert_eq!(
+            m.fixed_view::<2, 2>(1, 2),
+            Matrix::from([[7., 8.], [11., 12.]])
+        );
+        let mut m = m;
+        m.set_fixed_view(0, 1, &Matrix::from([[0., 0., 0.]]));
+        assert_eq!(m.as_vec()[0], vec![1., 0., 0., 0.]);
+        assert_eq!(m.as_vec()[1], vec![5., 6., 7., 8.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix: block out of bound")]
+    fn test_fixed_view_out_of_bound() {
+        sample().fixed_view::<2, 2>(2, 0);
+    }
+
+    #[test]
+    fn test_remove_and_stack() {
+        let m = sample();
+        let r: Matrix<f32, 2, 4> = m.remove_row(1).unwrap();
+        assert_eq!(r, Matrix::from([[1., 2., 3., 4.], [9., 10., 11., 12.]]));
+        let c: Matrix<f32, 3, 3> = m.remove_col(0).unwrap();
+        assert_eq!(c.as_vec()[2], vec![10., 11., 12.]);
+        assert_eq!(
+            m.remove_row::<1>(0),
+            Err(String::from("Expected K + 1 == 3 rows but K is 1"))
+        );
+        let empty: Matrix<f32, 0, 2> = Matrix::zeros();
+        assert_eq!(
+            empty.remove_row::<0>(0),
+            Err(String::from("Expected K + 1 == 0 rows but K is 0"))
+        );
+        assert_eq!(
+            Matrix::<f32, 2, 0>::zeros().remove_col::<0>(0),
+            Err(String::from("Expected K + 1 == 0 columns but K is 0"))
+        );
+        let left: Matrix<f32, 3, 1> = m.fixed_view(0, 0);
+        let right: Matrix<f32, 3, 3> = m.fixed_view(0, 1);
+        assert_eq!(left.hstack(&right), Ok(m.clone()));
+        let top: Matrix<f32, 1, 4> = m.fixed_view(0, 0);
+        assert_eq!(top.vstack(&m.fixed_view::<1, 4>(2, 0)), Ok(r.clone()));
+        assert!(left.hstack::<3, 5>(&right).is_err());
+    }
+
+    #[test]
+    fn test_minors_and_adjugate() {
+        let m = Matrix::from([[2., 0., 1.], [1., 3., 2.], [1., 1., 2.]]);
+        assert!(floats_are_equal(m.minor(0, 0), 4.));
+        assert!(floats_are_equal(m.minor(1, 0), -1.));
+        assert!(floats_are_equal(m.cofactor(1, 0), 1.));
+        let adj = m.adjugate();
+        let mut c = m.cofactor_matrix();
+        assert!(matrices_are_equal(&adj, &c.transpose()));
+        //a adj(a) = det(a) i
+        let det = m.clone().determinant();
+        assert!(matrices_are_equal(
+            &(m.clone() * adj.clone()),
+            &(TMatrix::identity() * det)
+        ));
+        assert!(matrices_are_equal(
+            &(adj * (1. / det)),
+            &m.clone().inverse().unwrap()
+        ));
+        //singular matrices still have an adjugate
+        let s = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(s.adjugate(), Matrix::from([[4., -2.], [-2., 1.]]));
+        assert_eq!(Matrix::from([[5.]]).adjugate(), Matrix::from([[1.]]));
+    }
+
+    #[test]
+    fn test_integer_minors() {
+        let mut m: TMatrix<i64, 3> = Matrix::from([[2, 3, 1], [4, 7, 5], [1, 2, 9]]);
+        assert_eq!(m.minor(0, 0), 53);
+        assert_eq!(m.cofactor(0, 1), -31);
+        let adj = m.adjugate();
+        let det = m.determinant();
+        assert_eq!(det, 14);
+        assert_eq!(m.clone() * adj.clone(), TMatrix::identity() * det);
+        assert_eq!(adj * m, TMatrix::identity() * det);
+    }
+
+    #[test]
+    fn test_cramer() {
+        //x_i = det(a with column i replaced by b) / det(a)
+        let a = Matrix::from([[2., 1., -1.], [-3., -1., 2.], [-2., 1., 2.]]);
+        let b = Vector::from([8., -11., -3.]);
+        let det = a.clone().determinant();
+        let x: Vec<f32> = (0..3)
+            .map(|i| {
+                let mut ai = a.clone();
+                ai.set_fixed_view(0, i, &Matrix::<f32, 3, 1>::from_fn(|r, _| b[r]));
+                ai.determinant() / det
+            })
+            .collect();
+        for (xi, expected) in x.iter().zip([2., 3., -1.].iter()) {
+            assert!((xi - expected).abs() < 1e-5);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::base_structs::matrix::{Matrix, TMatrix};
+use crate::base_structs::vector::Vector;
+use crate::num_traits::scalar::Scalar;
+
+impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
+    fn check_row(i: usize) {
+        if i >= M {
+            panic!("Matrix: index out of bound");
+        }
+    }
+
+    fn check_col(j: usize) {
+        if j >= N {
+            panic!("Matrix: index out of bound");
+        }
+    }
+
+    pub fn row(&self, i: usize) -> Vector<T, N> {
+        Matrix::<T, M, N>::check_row(i);
+        Vector::from_fn(|c| self.data[i][c])
+    }
+
+    pub fn col(&self, j: usize) -> Vector<T, M> {
+        Matrix::<T, M, N>::check_col(j);
+        Vector::from_fn(|r| self.data[r][j])
+    }
+
+    pub fn set_row(&mut self, i: usize, v: &Vector<T, N>) {
+        Matrix::<T, M, N>::check_row(i);
+        for c in 0..N {
+            self.data[i][c] = v[c];
+        }
+    }
+
+    pub fn set_col(&mut self, j: usize, v: &Vector<T, M>) {
+        Matrix::<T, M, N>::check_col(j);
+        for r in 0..M {
+            self.data[r][j] = v[r];
+        }
+    }
+
+    //the three elementary row operations, each the same as multiplying by
+    //the matching elementary matrix from the left
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        Matrix::<T, M, N>::check_row(a);
+        Matrix::<T, M, N>::check_row(b);
+        self.data.swap(a, b);
+    }
+
+    pub fn scale_row(&mut self, i: usize, k: T) {
+        Matrix::<T, M, N>::check_row(i);
+        for item in self.data[i].iter_mut() {
+            *item = *item * k;
+        }
+    }
+
+    //row target += k * row source
+    pub fn add_row_multiple(&mut self, target: usize, source: usize, k: T) {
+        Matrix::<T, M, N>::check_row(target);
+        Matrix::<T, M, N>::check_row(source);
+        for c in 0..N {
+            let s = self.data[source][c];
+            self.data[target][c] = self.data[target][c] + k * s;
+        }
+    }
+
+    //same as multiplying by the swap matrix from the right
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        Matrix::<T, M, N>::check_col(a);
+        Matrix::<T, M, N>::check_col(b);
+        for row in self.data.iter_mut() {
+            row.swap(a, b);
+        }
+    }
+}
+
+impl<T: Scalar, const M: usize> TMatrix<T, M> {
+    //identity with rows a and b exchanged
+    pub fn elementary_swap(a: usize, b: usize) -> TMatrix<T, M> {
+        let mut m = TMatrix::identity();
+        m.swap_rows(a, b);
+        m
+    }
+
+    //identity with k at (i, i)
+    pub fn elementary_scale(i: usize, k: T) -> TMatrix<T, M> {
+        let mut m = TMatrix::identity();
+        m.scale_row(i, k);
+        m
+    }
+
+    //identity with k at (target, source)
+    pub fn elementary_add(target: usize, source: usize, k: T) -> TMatrix<T, M> {
+        let mut m = TMatrix::identity();
+        m.add_row_multiple(target, source, k);
+        m
+    }
+
+    //row i of P * A is row perm[i] of A
+    pub fn permutation(perm: &[usize; M]) -> Result<TMatrix<T, M>, String> {
+        let mut seen = [false; M];
+        for p in perm.iter() {
+            if *p >= M || seen[*p] {
+                return Err(String::from("not a permutation"));
+            }
+            seen[*p] = true;
+        }
+        Ok(Matrix::from_fn(|r, c| {
+            if perm[r] == c {
+                T::one()
+            } else {
+                T::zero()
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod row_operations {
+    use super::*;
+    use crate::utils::comp::matrices_are_equal;
+
+    fn sample() -> Matrix<f32, 3, 3> {
+        Matrix::from([[0., 2., 1.], [1., 1., 0.], [2., 1., 1.]])
+    }
+
+    #[test]
+    fn test_rows_and_cols() {
+        let mut m = sample();
+        assert_eq!(m.row(1), Vector::from([1., 1., 0.]));
+        assert_eq!(m.col(1), Vector::from([2., 1., 1.]));
+        m.set_row(0, &Vector::from([7., 8., 9.]));
+        m.set_col(2, &Vector::from([0., 0., 0.]));
+        assert_eq!(m, Matrix::from([[7., 8., 0.], [1., 1., 0.], [2., 1., 0.]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix: index out of bound")]
+    fn test_row_out_of_bound() {
+        sample().row(3);
+    }
+
+    #[test]
+    fn test_operations_match_elementary_matrices() {
+        let a = sample();
+        let mut m = a.clone();
+        m.swap_rows(0, 2);
+        assert_eq!(m, TMatrix::elementary_swap(0, 2) * a.clone());
+        let mut m = a.clone();
+        m.scale_row(1, 3.);
+        assert_eq!(m, TMatrix::elementary_scale(1, 3.) * a.clone());
+        let mut m = a.clone();
+        m.add_row_multiple(2, 1, -2.);
+        assert_eq!(m, TMatrix::elementary_add(2, 1, -2.) * a.clone());
+        let mut m = a.clone();
+        m.swap_cols(0, 1);
+        assert_eq!(m, a.clone() * TMatrix::elementary_swap(0, 1));
+    }
+
+    #[test]
+    fn test_permutation() {
+        let a = sample();
+        let p = TMatrix::permutation(&[1, 2, 0]).unwrap();
+        let pa = p.clone() * a.clone();
+        assert_eq!(pa.row(0), a.row(1));
+        assert_eq!(pa.row(2), a.row(0));
+        //permutation matrices are orthogonal
+        let mut pt = p.clone();
+        assert_eq!(pt.transpose() * p, TMatrix::identity());
+        assert_eq!(
+            TMatrix::<f32, 3>::permutation(&[0, 2, 2]),
+            Err(String::from("not a permutation"))
+        );
+    }
+
+    #[test]
+    fn test_gaussian_elimination_by_hand() {
+        //record the operations as matrices, their product times a is upper triangular
+        let a = sample();
+        let mut u = a.clone();
+        let mut e: TMatrix<f32, 3> = TMatrix::identity();
+        u.swap_rows(0, 1);
+        e = TMatrix::elementary_swap(0, 1) * e;
+        u.add_row_multiple(2, 0, -2.);
+        e = TMatrix::elementary_add(2, 0, -2.) * e;
+        u.add_row_multiple(2, 1, 0.5);
+        e = TMatrix::elementary_add(2, 1, 0.5) * e;
+        assert_eq!(u, Matrix::from([[1., 1., 0.], [0., 2., 1.], [0., 0., 1.5]]));
+        assert!(matrices_are_equal(&(e * a), &u));
+    }
+}
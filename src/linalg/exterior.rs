@@ -0,0 +1,195 @@
+use crate::base_structs::matrix::TMatrix;
+use crate::base_structs::vector::{TVector3, Vector};
+use crate::exercises::ex06::cross_product;
+use crate::num_traits::ring::Ring;
+use crate::num_traits::scalar::Scalar;
+
+//element of the second exterior power of R^n, stored by its components on
+//e_i ^ e_j for i < j in lexicographic order: (0, 1), (0, 2), ..., (1, 2), ...
+#[derive(PartialEq, Debug, Clone)]
+pub struct Bivector<T> {
+    dim: usize,
+    components: Vec<T>,
+}
+
+impl<T: Scalar> Bivector<T> {
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    pub fn components(&self) -> &[T] {
+        &self.components
+    }
+
+    //index into components of the pair i < j
+    fn index(&self, i: usize, j: usize) -> usize {
+        i * (2 * self.dim - i - 1) / 2 + (j - i - 1)
+    }
+
+    //coefficient of e_i ^ e_j, antisymmetric so get(j, i) == -get(i, j)
+    pub fn get(&self, i: usize, j: usize) -> T {
+        if i >= self.dim || j >= self.dim {
+            panic!("Bivector: index out of bound");
+        }
+        if i == j {
+            T::zero()
+        } else if i < j {
+            self.components[self.index(i, j)]
+        } else {
+            -self.components[self.index(j, i)]
+        }
+    }
+
+    //area of the parallelogram spanned by the two vectors
+    pub fn magnitude(&self) -> T {
+        self.components
+            .iter()
+            .fold(T::zero(), |acc, c| acc + *c * *c)
+            .sqrt()
+    }
+}
+
+impl<T: Scalar, const N: usize> Vector<T, N> {
+    //u ^ v, with components u_i v_j - u_j v_i
+    //in 3d these are the cross product components, reordered and with one sign flipped
+    pub fn wedge(&self, v: &Vector<T, N>) -> Bivector<T> {
+        let mut components = Vec::with_capacity(N * N.saturating_sub(1) / 2);
+        for i in 0..N {
+            for j in i + 1..N {
+                components.push(self[i] * v[j] - self[j] * v[i]);
+            }
+        }
+        Bivector { dim: N, components }
+    }
+}
+
+//a . (b x c), the signed volume of the parallelepiped
+pub fn scalar_triple_product<T: Scalar>(a: &TVector3<T>, b: &TVector3<T>, c: &TVector3<T>) -> T {
+    a.dot(&cross_product(b, c))
+}
+
+//a x (b x c) = b (a . c) - c (a . b)
+pub fn vector_triple_product<T: Scalar>(
+    a: &TVector3<T>,
+    b: &TVector3<T>,
+    c: &TVector3<T>,
+) -> TVector3<T> {
+    b.clone() * a.dot(c) - c.clone() * a.dot(b)
+}
+
+//the vector w with w . x = det(v_1, ..., v_n-1, x) for every x
+//perpendicular to all inputs, the cross product in 3d and a quarter turn in 2d
+pub fn generalized_cross<T: Ring, const N: usize>(
+    vectors: &[Vector<T, N>],
+) -> Result<Vector<T, N>, String> {
+    if N == 0 || vectors.len() != N - 1 {
+        return Err(format!(
+            "Expected {} vectors but got {}",
+            N.saturating_sub(1),
+            vectors.len()
+        ));
+    }
+    //cofactor expansion along the last row, which holds x
+    //the cofactors don't divide, so integer vectors give exact results
+    let m: TMatrix<T, N> =
+        TMatrix::from_fn(|r, c| if r + 1 < N { vectors[r][c] } else { T::zero() });
+    Ok(Vector::from_fn(|i| m.cofactor(N - 1, i)))
+}
+
+#[cfg(test)]
+mod exterior_products {
+    use super::*;
+    use crate::utils::comp::vectors_are_equal;
+
+    #[test]
+    fn test_wedge() {
+        let u = Vector::from([1., 2., 3.]);
+        let v = Vector::from([4., 5., 6.]);
+        let b = u.wedge(&v);
+        assert_eq!(b.components(), &[-3., -6., -3.]);
+        assert_eq!(b.get(1, 0), 3.);
+        assert_eq!(b.get(2, 2), 0.);
+        //same magnitude as the cross product
+        assert_eq!(b.magnitude(), cross_product(&u, &v).abs());
+        assert_eq!(u.wedge(&u).magnitude(), 0.);
+        let b = Vector::from([1., 0., 0., 0.]).wedge(&Vector::from([0., 0., 0., 2.]));
+        assert_eq!(b.components().len(), 6);
+        assert_eq!(b.get(0, 3), 2.);
+        assert_eq!(b.get(3, 0), -2.);
+        assert_eq!(b.components()[2], 2.);
+    }
+
+    #[test]
+    fn test_triple_products() {
+        let a = Vector::from([1., 0., 0.]);
+        let b = Vector::from([0., 2., 0.]);
+        let c = Vector::from([0., 0., 3.]);
+        assert_eq!(scalar_triple_product(&a, &b, &c), 6.);
+        assert_eq!(scalar_triple_product(&b, &a, &c), -6.);
+        let (a, b, c) = (
+            Vector::from([1., 2., 3.]),
+            Vector::from([-1., 0., 2.]),
+            Vector::from([4., 1., 1.]),
+        );
+        assert_eq!(
+            vector_triple_product(&a, &b, &c),
+            cross_product(&a, &cross_product(&b, &c))
+        );
+    }
+
+    #[test]
+    fn test_generalized_cross() {
+        let u = Vector::from([1., 2., 3.]);
+        let v = Vector::from([4., 5., 7.]);
+        assert!(vectors_are_equal(
+            &generalized_cross(&[u.clone(), v.clone()]).unwrap(),
+            &cross_product(&u, &v)
+        ));
+        assert_eq!(
+            generalized_cross(&[Vector::from([1., 0.])]).unwrap(),
+            Vector::from([0., 1.])
+        );
+        let vs = [
+            Vector::from([1., 0., 0., 0.]),
+            Vector::from([0., 1., 0., 0.]),
+            Vector::from([0., 0., 1., 0.]),
+        ];
+        assert_eq!(
+            generalized_cross(&vs).unwrap(),
+            Vector::from([0., 0., 0., 1.])
+        );
+        let vs = [
+            Vector::from([1., 2., 0., 1.]),
+            Vector::from([0., 1., 3., -1.]),
+            Vector::from([2., 0., 1., 1.]),
+        ];
+        let w = generalized_cross(&vs).unwrap();
+        for v in vs.iter() {
+            assert!(w.dot(v).abs() < 1e-5);
+        }
+        assert_eq!(
+            generalized_cross(&[u]),
+            Err(String::from("Expected 2 vectors but got 1"))
+        );
+    }
+
+    #[test]
+    fn test_generalized_cross_integers() {
+        let vs: [Vector<i64, 4>; 3] = [
+            Vector::from([1, 2, 0, 1]),
+            Vector::from([0, 1, 3, -1]),
+            Vector::from([2, 0, 1, 1]),
+        ];
+        let w = generalized_cross(&vs).unwrap();
+        assert_eq!(w, Vector::from([-9, -2, 5, 13]));
+        for v in vs.iter() {
+            assert_eq!((0..4).map(|i| w[i] * v[i]).sum::<i64>(), 0);
+        }
+        let u: Vector<i64, 3> = Vector::from([3, -1, 2]);
+        let v: Vector<i64, 3> = Vector::from([1, 4, -2]);
+        assert_eq!(
+            generalized_cross(&[u, v]).unwrap(),
+            Vector::from([-6, 8, 13])
+        );
+    }
+}
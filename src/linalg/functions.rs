@@ -0,0 +1,258 @@
+use crate::base_structs::dmatrix::DMatrix;
+use crate::base_structs::matrix::TMatrix;
+use crate::num_traits::ring::Ring;
+use crate::num_traits::scalar::Scalar;
+
+//gauss-jordan with partial pivoting, singular up to the rounding tolerance
+fn inverse<T: Scalar, const M: usize>(m: &TMatrix<T, M>) -> Result<TMatrix<T, M>, String> {
+    TMatrix::try_from(DMatrix::from(m.clone()).inverse()?)
+}
+
+impl<T: Ring, const M: usize> TMatrix<T, M> {
+    //repeated squaring, exact for integers
+    pub fn powu(&self, n: u32) -> TMatrix<T, M> {
+        let mut base = self.clone();
+        let mut e = n;
+        let mut res = TMatrix::identity();
+        while e > 0 {
+            if e & 1 == 1 {
+                res = res * base.clone();
+            }
+            e >>= 1;
+            //no square past the last bit, it could overflow when the result doesn't
+            if e > 0 {
+                base = base.clone() * base;
+            }
+        }
+        res
+    }
+}
+
+impl<T: Scalar, const M: usize> TMatrix<T, M> {
+    //n < 0 raises the inverse to -n, integer matrices only have powu
+    pub fn pow(&self, n: i32) -> Result<TMatrix<T, M>, String> {
+        if n < 0 {
+            Ok(inverse(self)?.powu(n.unsigned_abs()))
+        } else {
+            Ok(self.powu(n as u32))
+        }
+    }
+
+    //scaling and squaring with a [6/6] pade approximant (golub and van loan, algorithm 9.3.1):
+    //exp(a) = exp(a / 2^s)^(2^s), with s chosen so |a / 2^s| <= 1/2 where pade is accurate
+    //past 64 halvings the norm is infinite or the result overflows anyway
+    pub fn expm(&self) -> Result<TMatrix<T, M>, String> {
+        let (one, two) = (T::one(), T::one() + T::one());
+        let mut a = self.clone();
+        let mut s = 0;
+        while a.norm_inf() > one / two {
+            if s == 64 {
+                return Err(String::from("matrix norm is too large"));
+            }
+            a = a * (one / two);
+            s += 1;
+        }
+        let q = 6;
+        let mut c = one;
+        let mut x: TMatrix<T, M> = TMatrix::identity();
+        let mut num: TMatrix<T, M> = TMatrix::identity();
+        let mut den: TMatrix<T, M> = TMatrix::identity();
+        let mut sign = one;
+        //c_k = c_k-1 (q - k + 1) / (k (2q - k + 1))
+        let (mut k, mut qk, mut twoq_k) = (T::zero(), T::from_usize(q), T::from_usize(2 * q));
+        for _ in 0..q {
+            k = k + one;
+            c = c * qk / (k * twoq_k);
+            qk = qk - one;
+            twoq_k = twoq_k - one;
+            x = a.clone() * x;
+            sign = -sign;
+            num = num + x.clone() * c;
+            den = den + x.clone() * (sign * c);
+        }
+        //the denominator stays close to the identity for |a| <= 1/2
+        let mut res = inverse(&den)? * num;
+        for _ in 0..s {
+            res = res.clone() * res;
+        }
+        Ok(res)
+    }
+
+    //principal square root by the denman-beavers iteration, y -> sqrt(a) and z -> sqrt(a)^-1
+    //needs a to have no eigenvalues on the closed negative real axis
+    pub fn sqrtm(&self) -> Result<TMatrix<T, M>, String> {
+        let half = T::one() / (T::one() + T::one());
+        let mut y = self.clone();
+        let mut z: TMatrix<T, M> = TMatrix::identity();
+        let tol = T::epsilon().sqrt();
+        let mut close = false;
+        for _ in 0..100 {
+            let next_y = (y.clone() + inverse(&z)?) * half;
+            let next_z = (z.clone() + inverse(&y)?) * half;
+            let change = (next_y.clone() - y).norm_frobenius();
+            y = next_y;
+            z = next_z;
+            //convergence is quadratic, one more step after reaching sqrt(eps) gets to eps
+            if close {
+                return Ok(y);
+            }
+            close = change <= tol * y.norm_frobenius();
+        }
+        Err(String::from("iteration did not converge"))
+    }
+
+    //principal logarithm by inverse scaling and squaring: log(a) = 2^k log(a^(1/2^k)),
+    //with k square roots bringing a close to the identity, then the series
+    //log(a) = 2 (z + z^3 / 3 + z^5 / 5 + ...) with z = (a - i)(a + i)^-1
+    pub fn logm(&self) -> Result<TMatrix<T, M>, String> {
+        let one = T::one();
+        let quarter = one / (one + one + one + one);
+        let id: TMatrix<T, M> = TMatrix::identity();
+        let mut a = self.clone();
+        let mut scale = one;
+        let mut roots = 0;
+        while (a.clone() - id.clone()).norm_1() > quarter {
+            if roots == 40 {
+                return Err(String::from("iteration did not converge"));
+            }
+            a = a.sqrtm()?;
+            scale = scale + scale;
+            roots += 1;
+        }
+        let z = (a.clone() - id.clone()) * inverse(&(a + id))?;
+        let z2 = z.clone() * z.clone();
+        let mut term = z.clone();
+        let mut res = z;
+        let mut k = one;
+        //|z| <= 1/7 here, so 12 terms are well past single and double precision
+        for _ in 0..12 {
+            term = term * z2.clone();
+            k = k + one + one;
+            res = res + term.clone() * (one / k);
+        }
+        Ok(res * (scale + scale))
+    }
+}
+
+#[cfg(test)]
+mod matrix_functions {
+    use super::*;
+    use crate::base_structs::matrix::Matrix;
+
+    fn close<const M: usize>(a: &TMatrix<f32, M>, b: &TMatrix<f32, M>, tol: f32) -> bool {
+        (a.clone() - b.clone()).norm_max() <= tol * b.norm_max().max(1.)
+    }
+
+    #[test]
+    fn test_pow() {
+        let a = Matrix::from([[1., 1.], [1., 0.]]);
+        //fibonacci numbers
+        assert_eq!(a.pow(10).unwrap(), Matrix::from([[89., 55.], [55., 34.]]));
+        assert_eq!(a.pow(0).unwrap(), TMatrix::identity());
+        assert_eq!(a.pow(1).unwrap(), a);
+        let mut inv = a.clone();
+        let inv = inv.inverse().unwrap();
+        assert!(close(
+            &a.pow(-3).unwrap(),
+            &(inv.clone() * inv.clone() * inv),
+            1e-6
+        ));
+        let s = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(s.pow(-1), Err(String::from("matrix is singular")));
+        assert!(s.pow(2).is_ok());
+        //singular up to rounding, the determinant is a single ulp
+        let s = Matrix::from([[1., 1.], [1., 1. + f32::EPSILON]]);
+        assert_eq!(s.pow(-1), Err(String::from("matrix is singular")));
+        let a = Matrix::from([[4., -2., 1.], [3., 6., -4.], [2., 1., 8.]]);
+        assert!(close(
+            &(a.pow(-2).unwrap() * a.pow(2).unwrap()),
+            &TMatrix::identity(),
+            1e-5
+        ));
+    }
+
+    #[test]
+    fn test_powu() {
+        //f_91 is the largest fibonacci number that fits in i64 along with f_90
+        let a: TMatrix<i64, 2> = Matrix::from([[1, 1], [1, 0]]);
+        let f = a.powu(90);
+        assert_eq!(
+            f,
+            Matrix::from([
+                [4660046610375530309, 2880067194370816120],
+                [2880067194370816120, 1779979416004714189]
+            ])
+        );
+        assert_eq!(a.powu(0), TMatrix::identity());
+        assert_eq!(
+            Matrix::from([[2., 0.], [0., 3.]]).powu(3),
+            Matrix::from([[8., 0.], [0., 27.]])
+        );
+    }
+
+    #[test]
+    fn test_expm() {
+        let d = Matrix::from([[1., 0.], [0., -2.]]);
+        let e = Matrix::from([[1_f32.exp(), 0.], [0., (-2_f32).exp()]]);
+        assert!(close(&d.expm().unwrap(), &e, 1e-5));
+        //nilpotent: the series stops after the linear term
+        let n = Matrix::from([[0., 3.], [0., 0.]]);
+        assert!(close(
+            &n.expm().unwrap(),
+            &Matrix::from([[1., 3.], [0., 1.]]),
+            1e-6
+        ));
+        //exp of the rotation generator is a rotation
+        let t = 2.5_f32;
+        let g = Matrix::from([[0., -t], [t, 0.]]);
+        let r = Matrix::from([[t.cos(), -t.sin()], [t.sin(), t.cos()]]);
+        assert!(close(&g.expm().unwrap(), &r, 1e-5));
+        //exp(a) exp(-a) = i, and large norms go through many squarings
+        let a = Matrix::from([[1., 2., 0.], [-1., 3., 1.], [0.5, 0., -2.]]);
+        let prod = a.expm().unwrap() * (a.clone() * -1.).expm().unwrap();
+        assert!(close(&prod, &TMatrix::identity(), 1e-4));
+        assert_eq!(TMatrix::<f32, 3>::zeros().expm(), Ok(TMatrix::identity()));
+        let big = Matrix::from([[f32::INFINITY, 0.], [0., 1.]]);
+        assert_eq!(big.expm(), Err(String::from("matrix norm is too large")));
+    }
+
+    #[test]
+    fn test_sqrtm() {
+        let a = Matrix::from([[4., 0.], [0., 9.]]);
+        assert!(close(
+            &a.sqrtm().unwrap(),
+            &Matrix::from([[2., 0.], [0., 3.]]),
+            1e-6
+        ));
+        let a = Matrix::from([[5., 2., 0.], [2., 5., 1.], [0., 1., 3.]]);
+        let r = a.sqrtm().unwrap();
+        assert!(close(&(r.clone() * r), &a, 1e-5));
+        //a rotation by 90 degrees has a rotation by 45 degrees as its root
+        let a = Matrix::from([[0., -1.], [1., 0.]]);
+        let h = std::f32::consts::FRAC_1_SQRT_2;
+        assert!(close(
+            &a.sqrtm().unwrap(),
+            &Matrix::from([[h, -h], [h, h]]),
+            1e-5
+        ));
+        let s = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(s.sqrtm(), Err(String::from("matrix is singular")));
+    }
+
+    #[test]
+    fn test_logm() {
+        let a = Matrix::from([[1., 0.], [0., 1.]]);
+        assert!(close(&a.logm().unwrap(), &TMatrix::zeros(), 1e-6));
+        let e = Matrix::from([[1_f32.exp(), 0.], [0., 20.]]);
+        let l = e.logm().unwrap();
+        assert!(close(
+            &l,
+            &Matrix::from([[1., 0.], [0., 20_f32.ln()]]),
+            1e-4
+        ));
+        let a = Matrix::from([[0.5, 1., 0.], [-0.3, 0.2, 0.4], [0., 0.1, -0.6]]);
+        assert!(close(&a.expm().unwrap().logm().unwrap(), &a, 1e-3));
+        let s = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(s.logm(), Err(String::from("matrix is singular")));
+    }
+}
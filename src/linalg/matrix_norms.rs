@@ -0,0 +1,331 @@
+use crate::base_structs::matrix::{Matrix, TMatrix};
+use crate::num_traits::scalar::Scalar;
+
+//one-sided jacobi (hestenes): rotate pairs of columns until all of them are orthogonal,
+//the singular values are then the column lengths
+//works on the columns directly, so small singular values are not lost by squaring into a^T a
+fn singular_values<T: Scalar>(mut cols: Vec<Vec<T>>) -> Vec<T> {
+    let dot = |a: &[T], b: &[T]| {
+        a.iter()
+            .zip(b.iter())
+            .fold(T::zero(), |acc, (x, y)| acc + *x * *y)
+    };
+    let n = cols.len();
+    let two = T::one() + T::one();
+    for _ in 0..60 {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = dot(&cols[p], &cols[p]);
+                let beta = dot(&cols[q], &cols[q]);
+                let gamma = dot(&cols[p], &cols[q]);
+                if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() || gamma == T::zero() {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (two * gamma);
+                let sign = if zeta < T::zero() {
+                    -T::one()
+                } else {
+                    T::one()
+                };
+                let t = sign / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                let c = T::one() / (T::one() + t * t).sqrt();
+                let s = c * t;
+                for k in 0..cols[p].len() {
+                    let (x, y) = (cols[p][k], cols[q][k]);
+                    cols[p][k] = c * x - s * y;
+                    cols[q][k] = s * x + c * y;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+    let mut res: Vec<T> = cols.iter().map(|c| dot(c, c).sqrt()).collect();
+    res.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+    res
+}
+
+//pa = lu with partial pivoting, l has a unit diagonal and shares the storage with u
+struct Lu<T> {
+    lu: Vec<Vec<T>>,
+    perm: Vec<usize>,
+}
+
+impl<T: Scalar> Lu<T> {
+    fn new(mut lu: Vec<Vec<T>>) -> Result<Lu<T>, String> {
+        let n = lu.len();
+        let mut big = T::zero();
+        for row in lu.iter() {
+            for item in row.iter() {
+                if item.abs() > big {
+                    big = item.abs();
+                }
+            }
+        }
+        let tol = big * T::from_usize(n) * T::epsilon();
+        let mut perm: Vec<usize> = (0..n).collect();
+        for col in 0..n {
+            let mut pivot = col;
+            for r in col + 1..n {
+                if lu[r][col].abs() > lu[pivot][col].abs() {
+                    pivot = r;
+                }
+            }
+            if lu[pivot][col].abs() <= tol {
+                return Err(String::from("matrix is singular"));
+            }
+            lu.swap(col, pivot);
+            perm.swap(col, pivot);
+            let pivot = lu[col].clone();
+            for row in lu.iter_mut().skip(col + 1) {
+                let k = row[col] / pivot[col];
+                row[col] = k;
+                for (item, p) in row.iter_mut().zip(pivot.iter()).skip(col + 1) {
+                    *item = *item - k * *p;
+                }
+            }
+        }
+        Ok(Lu { lu, perm })
+    }
+
+    fn solve(&self, b: &[T]) -> Vec<T> {
+        let n = self.lu.len();
+        let mut x: Vec<T> = self.perm.iter().map(|p| b[*p]).collect();
+        for r in 0..n {
+            for c in 0..r {
+                x[r] = x[r] - self.lu[r][c] * x[c];
+            }
+        }
+        for r in (0..n).rev() {
+            for c in r + 1..n {
+                x[r] = x[r] - self.lu[r][c] * x[c];
+            }
+            x[r] = x[r] / self.lu[r][r];
+        }
+        x
+    }
+
+    //a^T x = b with the same factors: a^T = u^T l^T p, so u^T and l^T are solved
+    //in that order and the permutation is undone at the end
+    fn solve_transposed(&self, b: &[T]) -> Vec<T> {
+        let n = self.lu.len();
+        let mut w = b.to_vec();
+        for r in 0..n {
+            for c in 0..r {
+                w[r] = w[r] - self.lu[c][r] * w[c];
+            }
+            w[r] = w[r] / self.lu[r][r];
+        }
+        for r in (0..n).rev() {
+            for c in r + 1..n {
+                w[r] = w[r] - self.lu[c][r] * w[c];
+            }
+        }
+        let mut x = vec![T::zero(); n];
+        for (i, p) in self.perm.iter().enumerate() {
+            x[*p] = w[i];
+        }
+        x
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
+    //square root of the sum of all squared entries
+    pub fn norm_frobenius(&self) -> T {
+        self.data
+            .iter()
+            .flatten()
+            .fold(T::zero(), |acc, x| acc + *x * *x)
+            .sqrt()
+    }
+
+    //largest absolute column sum
+    pub fn norm_1(&self) -> T {
+        (0..N).fold(T::zero(), |big, c| {
+            let sum = (0..M).fold(T::zero(), |acc, r| acc + self.data[r][c].abs());
+            if sum > big {
+                sum
+            } else {
+                big
+            }
+        })
+    }
+
+    //largest absolute row sum
+    pub fn norm_inf(&self) -> T {
+        self.data.iter().fold(T::zero(), |big, row| {
+            let sum = row.iter().fold(T::zero(), |acc, x| acc + x.abs());
+            if sum > big {
+                sum
+            } else {
+                big
+            }
+        })
+    }
+
+    //largest absolute entry, not submultiplicative
+    pub fn norm_max(&self) -> T {
+        self.data.iter().flatten().fold(
+            T::zero(),
+            |big, x| {
+                if x.abs() > big {
+                    x.abs()
+                } else {
+                    big
+                }
+            },
+        )
+    }
+
+    //in decreasing order, min(M, N) of them
+    pub fn singular_values(&self) -> Vec<T> {
+        let d = self.as_vec();
+        let cols = if M >= N {
+            (0..N).map(|c| (0..M).map(|r| d[r][c]).collect()).collect()
+        } else {
+            d
+        };
+        singular_values(cols)
+    }
+
+    //spectral norm, the largest singular value
+    pub fn norm_2(&self) -> T {
+        self.singular_values().first().copied().unwrap_or(T::zero())
+    }
+}
+
+impl<T: Scalar, const M: usize> TMatrix<T, M> {
+    //largest over smallest singular value, how much relative errors in b
+    //can grow in the solution of a x = b
+    pub fn cond_2(&self) -> Result<T, String> {
+        let s = self.singular_values();
+        match (s.first(), s.last()) {
+            (Some(big), Some(small)) if *small > *big * T::epsilon() => Ok(*big / *small),
+            (Some(_), Some(_)) => Err(String::from("matrix is singular")),
+            _ => Ok(T::one()),
+        }
+    }
+
+    //estimate of |a|_1 |a^-1|_1 without forming the inverse (hager's method, as in lapack's xlacon)
+    //a lower bound that is almost always within a factor of 3 of the real value
+    pub fn cond_1_estimate(&self) -> Result<T, String> {
+        if M == 0 {
+            return Ok(T::one());
+        }
+        let lu = Lu::new(self.as_vec())?;
+        let mut x = vec![T::one() / T::from_usize(M); M];
+        let mut estimate = T::zero();
+        let mut last = M;
+        for _ in 0..5 {
+            let y = lu.solve(&x);
+            estimate = y.iter().fold(T::zero(), |acc, v| acc + v.abs());
+            let sign: Vec<T> = y
+                .iter()
+                .map(|v| if *v < T::zero() { -T::one() } else { T::one() })
+                .collect();
+            let z = lu.solve_transposed(&sign);
+            let mut j = 0;
+            for (i, v) in z.iter().enumerate() {
+                if v.abs() > z[j].abs() {
+                    j = i;
+                }
+            }
+            let zx = z
+                .iter()
+                .zip(x.iter())
+                .fold(T::zero(), |acc, (a, b)| acc + *a * *b);
+            if z[j].abs() <= zx || j == last {
+                break;
+            }
+            x = vec![T::zero(); M];
+            x[j] = T::one();
+            last = j;
+        }
+        Ok(self.norm_1() * estimate)
+    }
+}
+
+#[cfg(test)]
+mod operator_norms {
+    use super::*;
+    use crate::utils::comp::floats_are_equal;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() <= 1e-4 * b.abs().max(1.)
+    }
+
+    #[test]
+    fn test_entrywise_norms() {
+        let m = Matrix::from([[1., -2., 3.], [-4., 5., -6.]]);
+        assert!(floats_are_equal(m.norm_frobenius(), 91_f32.sqrt()));
+        assert_eq!(m.norm_1(), 9.);
+        assert_eq!(m.norm_inf(), 15.);
+        assert_eq!(m.norm_max(), 6.);
+    }
+
+    #[test]
+    fn test_singular_values() {
+        let m = Matrix::from([[3., 0.], [0., -5.]]);
+        assert_eq!(m.singular_values(), vec![5., 3.]);
+        assert_eq!(m.norm_2(), 5.);
+        //singular values of [[3, 2, 2], [2, 3, -2]] are 5 and 3
+        let m = Matrix::from([[3., 2., 2.], [2., 3., -2.]]);
+        let s = m.singular_values();
+        assert!(close(s[0], 5.) && close(s[1], 3.));
+        let mut t = m.clone();
+        let s = t.transpose().singular_values();
+        assert!(close(s[0], 5.) && close(s[1], 3.));
+        //the spectral norm is bounded by the frobenius norm
+        assert!(m.norm_2() <= m.norm_frobenius());
+    }
+
+    #[test]
+    fn test_cond_2() {
+        let m = Matrix::from([[2., 0.], [0., 0.5]]);
+        assert!(close(m.cond_2().unwrap(), 4.));
+        assert!(close(TMatrix::<f32, 3>::identity().cond_2().unwrap(), 1.));
+        let singular = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(singular.cond_2(), Err(String::from("matrix is singular")));
+    }
+
+    #[test]
+    fn test_solve_transposed() {
+        //needs a row swap, so the permutation matters
+        let rows = vec![vec![1., 2., 0.], vec![4., 1., 3.], vec![2., 5., 1.]];
+        let lu = Lu::new(rows.clone()).unwrap();
+        let x = [1., -2., 3.];
+        let b: Vec<f32> = (0..3)
+            .map(|c| (0..3).fold(0., |acc, r| acc + rows[r][c] * x[r]))
+            .collect();
+        let got = lu.solve_transposed(&b);
+        for i in 0..3 {
+            assert!(close(got[i], x[i]));
+        }
+    }
+
+    #[test]
+    fn test_cond_1_estimate() {
+        let m = Matrix::from([[4., 1., 0.], [1., 3., 1.], [0., 1., 2.]]);
+        let mut exact_inv = m.clone();
+        let exact = m.norm_1() * exact_inv.inverse().unwrap().norm_1();
+        let est = m.cond_1_estimate().unwrap();
+        assert!(est <= exact * 1.0001);
+        assert!(est >= exact / 3.);
+        //ill conditioned: nearly dependent rows
+        let m = Matrix::from([[1., 1.], [1., 1.0001]]);
+        let mut inv = m.clone();
+        let exact = m.norm_1() * inv.inverse().unwrap().norm_1();
+        let est = m.cond_1_estimate().unwrap();
+        assert!(est > 1e4);
+        assert!(est <= exact * 1.01 && est >= exact / 3.);
+        assert_eq!(TMatrix::<f32, 0>::identity().cond_1_estimate(), Ok(1.));
+        let singular = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(
+            singular.cond_1_estimate(),
+            Err(String::from("matrix is singular"))
+        );
+    }
+}
@@ -0,0 +1,12 @@
+pub mod bareiss;
+pub mod blocks;
+pub mod elementary;
+pub mod exterior;
+pub mod functions;
+pub mod matrix_norms;
+pub mod normal_forms;
+pub mod orthonormal;
+pub mod polynomial;
+pub mod products;
+pub mod projection;
+pub mod vector_norms;
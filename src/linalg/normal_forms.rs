@@ -0,0 +1,398 @@
+use crate::base_structs::matrix::{Matrix, TMatrix};
+use crate::num_traits::integer::Integer;
+
+//integer row and column operations keep a unimodular (determinant +-1) record
+//of everything applied, so no division ever happens
+
+//h = u a
+#[derive(PartialEq, Debug, Clone)]
+pub struct HermiteForm<T, const M: usize, const N: usize> {
+    pub h: Matrix<T, M, N>,
+    pub u: TMatrix<T, M>,
+}
+
+//d = u a v
+#[derive(PartialEq, Debug, Clone)]
+pub struct SmithForm<T, const M: usize, const N: usize> {
+    pub d: Matrix<T, M, N>,
+    pub u: TMatrix<T, M>,
+    pub v: TMatrix<T, N>,
+}
+
+fn overflow() -> String {
+    String::from("integer overflow")
+}
+
+fn identity<T: Integer>(n: usize) -> Vec<Vec<T>> {
+    (0..n)
+        .map(|r| {
+            (0..n)
+                .map(|c| if r == c { T::one() } else { T::zero() })
+                .collect()
+        })
+        .collect()
+}
+
+fn neg<T: Integer>(a: &T) -> Result<T, String> {
+    T::zero().checked_sub(a).ok_or_else(overflow)
+}
+
+//Err on the one overflowing quotient, the smallest value divided by -1
+fn div<T: Integer>(a: &T, b: &T) -> Result<T, String> {
+    a.checked_div(b).ok_or_else(overflow)
+}
+
+//a - q b
+fn sub_mul<T: Integer>(a: &T, q: &T, b: &T) -> Result<T, String> {
+    let qb = q.checked_mul(b).ok_or_else(overflow)?;
+    a.checked_sub(&qb).ok_or_else(overflow)
+}
+
+//(g, x, y) with a x + b y = g = gcd(a, b) >= 0, the coefficients are bounded by |a| and |b|
+//Err only when g is one past the largest value, like gcd(MIN, 0)
+fn extended_gcd<T: Integer>(a: T, b: T) -> Result<(T, T, T), String> {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::one(), T::zero());
+    let (mut y0, mut y1) = (T::zero(), T::one());
+    while !r1.is_zero() {
+        let q = div(&r0, &r1)?;
+        let r = sub_mul(&r0, &q, &r1)?;
+        let x = sub_mul(&x0, &q, &x1)?;
+        let y = sub_mul(&y0, &q, &y1)?;
+        (r0, r1) = (r1, r);
+        (x0, x1) = (x1, x);
+        (y0, y1) = (y1, y);
+    }
+    if r0 < T::zero() {
+        Ok((neg(&r0)?, neg(&x0)?, neg(&y0)?))
+    } else {
+        Ok((r0, x0, y0))
+    }
+}
+
+//-|a|, which unlike |a| always fits
+fn neg_abs<T: Integer>(a: &T) -> T {
+    if *a > T::zero() {
+        //cannot overflow for a positive a
+        neg(a).unwrap()
+    } else {
+        a.clone()
+    }
+}
+
+//p != 0 divides a, -1 is handled apart because MIN / -1 overflows
+fn divides<T: Integer>(p: &T, a: &T) -> Result<bool, String> {
+    if T::one().checked_add(p).is_some_and(|s| s.is_zero()) {
+        return Ok(true);
+    }
+    Ok(sub_mul(a, &div(a, p)?, p)?.is_zero())
+}
+
+//largest q with q b <= a, for b > 0
+fn floor_div<T: Integer>(a: &T, b: &T) -> Result<T, String> {
+    let q = div(a, b)?;
+    if sub_mul(a, &q, b)? < T::zero() {
+        q.checked_sub(&T::one()).ok_or_else(overflow)
+    } else {
+        Ok(q)
+    }
+}
+
+fn mul_add<T: Integer>(a: &T, x: &T, b: &T, y: &T) -> Result<T, String> {
+    let ax = a.checked_mul(x).ok_or_else(overflow)?;
+    let by = b.checked_mul(y).ok_or_else(overflow)?;
+    ax.checked_add(&by).ok_or_else(overflow)
+}
+
+//rows r and i become (x r + y i, p r + q i), unimodular when x q - y p = +-1
+fn combine_rows<T: Integer>(
+    d: &mut [Vec<T>],
+    r: usize,
+    i: usize,
+    c: &[T; 4],
+) -> Result<(), String> {
+    for j in 0..d[r].len() {
+        let a = mul_add(&c[0], &d[r][j], &c[1], &d[i][j])?;
+        let b = mul_add(&c[2], &d[r][j], &c[3], &d[i][j])?;
+        d[r][j] = a;
+        d[i][j] = b;
+    }
+    Ok(())
+}
+
+//row target += k * row source
+fn add_row<T: Integer>(
+    d: &mut [Vec<T>],
+    target: usize,
+    source: usize,
+    k: &T,
+) -> Result<(), String> {
+    for j in 0..d[target].len() {
+        d[target][j] = mul_add(&T::one(), &d[target][j], k, &d[source][j])?;
+    }
+    Ok(())
+}
+
+fn add_col<T: Integer>(
+    d: &mut [Vec<T>],
+    target: usize,
+    source: usize,
+    k: &T,
+) -> Result<(), String> {
+    for row in d.iter_mut() {
+        row[target] = mul_add(&T::one(), &row[target], k, &row[source])?;
+    }
+    Ok(())
+}
+
+fn negate_row<T: Integer>(d: &mut [Vec<T>], r: usize) -> Result<(), String> {
+    for item in d[r].iter_mut() {
+        *item = neg(item)?;
+    }
+    Ok(())
+}
+
+fn swap_cols<T>(d: &mut [Vec<T>], a: usize, b: usize) {
+    for row in d.iter_mut() {
+        row.swap(a, b);
+    }
+}
+
+fn to_matrix<T: Integer, const M: usize, const N: usize>(d: &[Vec<T>]) -> Matrix<T, M, N> {
+    Matrix::from_fn(|r, c| d[r][c].clone())
+}
+
+impl<T: Integer, const M: usize, const N: usize> Matrix<T, M, N> {
+    //row style hermite normal form h = u a with u unimodular
+    //h is in row echelon form, pivots are positive and the entries above
+    //a pivot are in [0, pivot)
+    pub fn hermite_normal_form(&self) -> Result<HermiteForm<T, M, N>, String> {
+        let mut d = self.data.clone();
+        let mut u = identity(M);
+        let mut row = 0;
+        for col in 0..N {
+            if row == M {
+                break;
+            }
+            //gcd steps clear the column below the pivot and leave the gcd on the pivot
+            for i in row + 1..M {
+                let (a, b) = (d[row][col].clone(), d[i][col].clone());
+                if b.is_zero() {
+                    continue;
+                }
+                let (g, x, y) = extended_gcd(a.clone(), b.clone())?;
+                let c = [x, y, neg(&div(&b, &g)?)?, div(&a, &g)?];
+                combine_rows(&mut d, row, i, &c)?;
+                combine_rows(&mut u, row, i, &c)?;
+            }
+            if d[row][col].is_zero() {
+                continue;
+            }
+            if d[row][col] < T::zero() {
+                negate_row(&mut d, row)?;
+                negate_row(&mut u, row)?;
+            }
+            let p = d[row][col].clone();
+            for k in 0..row {
+                let q = neg(&floor_div(&d[k][col], &p)?)?;
+                add_row(&mut d, k, row, &q)?;
+                add_row(&mut u, k, row, &q)?;
+            }
+            row += 1;
+        }
+        Ok(HermiteForm {
+            h: to_matrix(&d),
+            u: to_matrix(&u),
+        })
+    }
+
+    //d = u a v with u and v unimodular and d diagonal, with
+    //non-negative entries each dividing the next
+    pub fn smith_normal_form(&self) -> Result<SmithForm<T, M, N>, String> {
+        let mut d = self.data.clone();
+        let mut u = identity(M);
+        let mut v = identity(N);
+        'diagonal: for t in 0..M.min(N) {
+            loop {
+                //the smallest non-zero entry left becomes the pivot
+                let mut best: Option<(usize, usize)> = None;
+                for i in t..M {
+                    for j in t..N {
+                        if !d[i][j].is_zero()
+                            && best.is_none_or(|(bi, bj)| neg_abs(&d[i][j]) > neg_abs(&d[bi][bj]))
+                        {
+                            best = Some((i, j));
+                        }
+                    }
+                }
+                let (bi, bj) = match best {
+                    Some(b) => b,
+                    None => break 'diagonal,
+                };
+                d.swap(t, bi);
+                u.swap(t, bi);
+                swap_cols(&mut d, t, bj);
+                swap_cols(&mut v, t, bj);
+                //division with remainder, a non-zero remainder is smaller than the pivot
+                let mut clean = true;
+                for i in t + 1..M {
+                    let q = neg(&div(&d[i][t], &d[t][t])?)?;
+                    add_row(&mut d, i, t, &q)?;
+                    add_row(&mut u, i, t, &q)?;
+                    clean = clean && d[i][t].is_zero();
+                }
+                for j in t + 1..N {
+                    let q = neg(&div(&d[t][j], &d[t][t])?)?;
+                    add_col(&mut d, j, t, &q)?;
+                    add_col(&mut v, j, t, &q)?;
+                    clean = clean && d[t][j].is_zero();
+                }
+                if !clean {
+                    continue;
+                }
+                //the pivot has to divide everything left, otherwise pull in an offending row
+                let mut offending = None;
+                'search: for i in t + 1..M {
+                    for j in t + 1..N {
+                        if !divides(&d[t][t], &d[i][j])? {
+                            offending = Some(i);
+                            break 'search;
+                        }
+                    }
+                }
+                match offending {
+                    Some(i) => {
+                        add_row(&mut d, t, i, &T::one())?;
+                        add_row(&mut u, t, i, &T::one())?;
+                    }
+                    None => break,
+                }
+            }
+            if d[t][t] < T::zero() {
+                negate_row(&mut d, t)?;
+                negate_row(&mut u, t)?;
+            }
+        }
+        Ok(SmithForm {
+            d: to_matrix(&d),
+            u: to_matrix(&u),
+            v: to_matrix(&v),
+        })
+    }
+}
+
+#[cfg(test)]
+mod integer_forms {
+    use super::*;
+    use num::BigInt;
+
+    fn is_unimodular<const M: usize>(m: &TMatrix<i64, M>) -> bool {
+        let d = m.determinant_bareiss().unwrap();
+        d == 1 || d == -1
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!(extended_gcd(240_i64, 46), Ok((2, -9, 47)));
+        assert_eq!(extended_gcd(0_i64, -5), Ok((5, 0, -1)));
+        assert_eq!(extended_gcd(-4_i64, 6).unwrap().0, 2);
+        assert_eq!(floor_div(&-7_i64, &2), Ok(-4));
+        assert_eq!(floor_div(&7_i64, &2), Ok(3));
+        //gcd(MIN, 0) = 2^63 doesn't fit
+        assert_eq!(
+            extended_gcd(i64::MIN, 0),
+            Err(String::from("integer overflow"))
+        );
+        let (g, x, y) = extended_gcd(i64::MIN, 6).unwrap();
+        assert_eq!(g, 2);
+        assert_eq!(i64::MIN as i128 * x as i128 + 6 * y as i128, 2);
+        assert_eq!(floor_div(&i64::MIN, &1), Ok(i64::MIN));
+    }
+
+    #[test]
+    fn test_hermite() {
+        let a: Matrix<i64, 3, 4> = Matrix::from([[2, 3, 6, 2], [5, 6, 1, 6], [8, 3, 1, 1]]);
+        let HermiteForm { h, u } = a.hermite_normal_form().unwrap();
+        assert_eq!(
+            h,
+            Matrix::from([[1, 0, 50, -11], [0, 3, 28, -2], [0, 0, 61, -13]])
+        );
+        assert_eq!(u.clone() * a, h);
+        assert!(is_unimodular(&u));
+    }
+
+    #[test]
+    fn test_hermite_rank_deficient() {
+        let a: Matrix<i64, 3, 3> = Matrix::from([[2, 4, 6], [1, 2, 3], [0, 0, -4]]);
+        let HermiteForm { h, u } = a.hermite_normal_form().unwrap();
+        assert_eq!(h, Matrix::from([[1, 2, 3], [0, 0, 4], [0, 0, 0]]));
+        assert_eq!(u.clone() * a, h);
+        assert!(is_unimodular(&u));
+    }
+
+    #[test]
+    fn test_smith() {
+        let a: TMatrix<i64, 3> = Matrix::from([[2, 4, 4], [-6, 6, 12], [10, -4, -16]]);
+        let SmithForm { d, u, v } = a.smith_normal_form().unwrap();
+        assert_eq!(d, Matrix::from([[2, 0, 0], [0, 6, 0], [0, 0, 12]]));
+        assert_eq!(u.clone() * a * v.clone(), d);
+        assert!(is_unimodular(&u) && is_unimodular(&v));
+    }
+
+    #[test]
+    fn test_smith_rectangular() {
+        //z^2 / <(2, 0), (0, 3), (2, 3)> is z_6: invariant factors 1 and 6
+        let a: Matrix<i64, 3, 2> = Matrix::from([[2, 0], [0, 3], [2, 3]]);
+        let SmithForm { d, u, v } = a.smith_normal_form().unwrap();
+        assert_eq!(d, Matrix::from([[1, 0], [0, 6], [0, 0]]));
+        assert_eq!(u.clone() * a * v.clone(), d);
+        assert!(is_unimodular(&u) && is_unimodular(&v));
+        let z: Matrix<i64, 2, 3> = Matrix::zeros();
+        assert_eq!(z.smith_normal_form().unwrap().d, z);
+    }
+
+    #[test]
+    fn test_overflow() {
+        //coprime diagonal entries combine into their product, which doesn't fit in i64
+        let a: TMatrix<i64, 2> = Matrix::from([[1 << 40, 0], [0, 3_i64.pow(25)]]);
+        assert_eq!(
+            a.smith_normal_form().map(|r| r.d),
+            Err(String::from("integer overflow"))
+        );
+        assert!(a.hermite_normal_form().is_ok());
+    }
+
+    #[test]
+    fn test_smallest_value() {
+        //the invariant factors 3 * 2^63 and 2^63 don't fit, but nothing may panic on the way
+        let a: TMatrix<i64, 2> = Matrix::from([[i64::MIN, 0], [0, 3]]);
+        assert_eq!(
+            a.smith_normal_form().map(|r| r.d),
+            Err(String::from("integer overflow"))
+        );
+        let a: TMatrix<i64, 2> = Matrix::from([[-1, 0], [0, i64::MIN]]);
+        assert_eq!(
+            a.smith_normal_form().map(|r| r.d),
+            Err(String::from("integer overflow"))
+        );
+        assert_eq!(divides(&-1, &i64::MIN), Ok(true));
+        assert_eq!(divides(&3, &i64::MIN), Ok(false));
+        assert_eq!(neg_abs(&i64::MIN), i64::MIN);
+        //-2^62 fits after negation
+        let a: TMatrix<i64, 2> = Matrix::from([[i64::MIN / 2, 0], [0, -1]]);
+        assert_eq!(
+            a.smith_normal_form().map(|r| r.d),
+            Ok(Matrix::from([[1, 0], [0, 1 << 62]]))
+        );
+    }
+    #[test]
+    fn test_big_integers() {
+        //the product that overflows i64 in test_overflow
+        let big = |v: i64| BigInt::from(v);
+        let a: TMatrix<BigInt, 2> =
+            Matrix::from([[big(1 << 40), big(0)], [big(0), big(3_i64.pow(25))]]);
+        let SmithForm { d, .. } = a.smith_normal_form().unwrap();
+        let product = BigInt::from(1_i64 << 40) * BigInt::from(3_i64.pow(25));
+        assert_eq!(d, Matrix::from([[big(1), big(0)], [big(0), product]]));
+    }
+}
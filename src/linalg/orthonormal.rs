@@ -0,0 +1,124 @@
+use crate::base_structs::matrix::{Matrix, TMatrix};
+use crate::base_structs::vector::Vector;
+use crate::num_traits::scalar::Scalar;
+
+impl<T: Scalar, const N: usize> Vector<T, N> {
+    //modified gram-schmidt: each vector has the already accepted directions removed one at a time,
+    //which keeps the result orthogonal in floating point where the classical version drifts
+    //a vector is dropped as dependent when less than tolerance of its length is left
+    pub fn gram_schmidt(vectors: &[Vector<T, N>], tolerance: T) -> Vec<Vector<T, N>> {
+        let mut basis: Vec<Vector<T, N>> = Vec::new();
+        for v in vectors.iter() {
+            if basis.len() == N {
+                break;
+            }
+            let mut w = v.clone();
+            for q in basis.iter() {
+                let c = q.dot(&w);
+                w = Vector::linear_combination(&[w, q.clone()], &[T::one(), -c]);
+            }
+            let len = w.abs();
+            if len > tolerance * v.abs() {
+                basis.push(w.normalize());
+            }
+        }
+        basis
+    }
+
+    //orthonormalizes partial and fills it up to N vectors with the coordinate axes
+    pub fn complete_basis(partial: &[Vector<T, N>], tolerance: T) -> Vec<Vector<T, N>> {
+        let mut candidates = partial.to_vec();
+        candidates.extend((0..N).map(Vector::unit));
+        Vector::gram_schmidt(&candidates, tolerance)
+    }
+}
+
+impl<T: Scalar, const M: usize> TMatrix<T, M> {
+    //gram-schmidt on the columns, the result is orthogonal and column k spans
+    //the same space as the first k + 1 columns of self
+    pub fn orthonormalize(&self) -> Result<TMatrix<T, M>, String> {
+        let d = self.as_vec();
+        let cols: Vec<Vector<T, M>> = (0..M).map(|c| Vector::from_fn(|r| d[r][c])).collect();
+        let tolerance = T::epsilon().sqrt();
+        let basis = Vector::gram_schmidt(&cols, tolerance);
+        if basis.len() < M {
+            return Err(String::from("matrix is singular"));
+        }
+        Ok(Matrix::from_cols(&basis))
+    }
+}
+
+#[cfg(test)]
+mod gram_schmidt {
+    use super::*;
+    use crate::utils::comp::{floats_are_equal, matrices_are_equal, vectors_are_equal};
+
+    fn is_orthonormal<const N: usize>(basis: &[Vector<f32, N>]) -> bool {
+        basis.iter().enumerate().all(|(i, u)| {
+            basis.iter().enumerate().all(|(j, v)| {
+                let expected = if i == j { 1. } else { 0. };
+                (u.dot(v) - expected).abs() < 1e-5
+            })
+        })
+    }
+
+    #[test]
+    fn test_gram_schmidt() {
+        let vs = [
+            Vector::from([3., 0., 0.]),
+            Vector::from([1., 2., 0.]),
+            Vector::from([1., 1., 5.]),
+        ];
+        let q = Vector::gram_schmidt(&vs, 1e-6);
+        assert_eq!(q.len(), 3);
+        assert!(is_orthonormal(&q));
+        assert_eq!(q[0], Vector::from([1., 0., 0.]));
+        assert_eq!(q[1], Vector::from([0., 1., 0.]));
+        assert_eq!(q[2], Vector::from([0., 0., 1.]));
+    }
+
+    #[test]
+    fn test_gram_schmidt_drops_dependent() {
+        let vs = [
+            Vector::from([1., 1., 0.]),
+            Vector::from([2., 2., 0.]),
+            Vector::from([0., 0., 0.]),
+            Vector::from([1., 0., 0.]),
+            //within the tolerance of the plane spanned so far
+            Vector::from([1., 1., 1e-5]),
+        ];
+        let q = Vector::gram_schmidt(&vs, 1e-3);
+        assert_eq!(q.len(), 2);
+        assert!(is_orthonormal(&q));
+        let s = std::f32::consts::FRAC_1_SQRT_2;
+        assert!(vectors_are_equal(&q[1], &Vector::from([s, -s, 0.])));
+    }
+
+    #[test]
+    fn test_complete_basis() {
+        let q = Vector::complete_basis(&[Vector::from([1., 1., 1., 1.])], 1e-6);
+        assert_eq!(q.len(), 4);
+        assert!(is_orthonormal(&q));
+        assert!(floats_are_equal(q[0][0], 0.5));
+        let q: Vec<Vector<f32, 2>> = Vector::complete_basis(&[], 1e-6);
+        assert_eq!(q, vec![Vector::from([1., 0.]), Vector::from([0., 1.])]);
+    }
+
+    #[test]
+    fn test_orthonormalize() {
+        let m = Matrix::from([[2., 1., 0.], [0., 1., 1.], [0., 0., 3.]]);
+        let q = m.orthonormalize().unwrap();
+        let mut qt = q.clone();
+        assert!(matrices_are_equal(
+            &(qt.transpose() * q.clone()),
+            &TMatrix::identity()
+        ));
+        //upper triangular input keeps the coordinate axes
+        assert!(matrices_are_equal(&q, &TMatrix::identity()));
+        let singular = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(
+            singular.orthonormalize(),
+            Err(String::from("matrix is singular"))
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use crate::base_structs::matrix::TMatrix;
+use crate::num_traits::scalar::Scalar;
+
+//coefficients go from the highest degree down, [1, -3, 2] is x^2 - 3x + 2
+pub fn horner<T: Scalar>(coeffs: &[T], x: T) -> T {
+    coeffs.iter().fold(T::zero(), |acc, c| acc * x + *c)
+}
+
+impl<T: Scalar, const M: usize> TMatrix<T, M> {
+    //det(x i - a), highest degree first with a leading 1, so M + 1 coefficients
+    //faddeev-leverrier: m_k = a m_k-1 + c_k-1 i and c_k = -tr(a m_k) / k,
+    //only divides by k so it stays exact for integer matrices
+    pub fn characteristic_polynomial(&self) -> Vec<T> {
+        let id: TMatrix<T, M> = TMatrix::identity();
+        let mut coeffs = vec![T::one()];
+        let mut m: TMatrix<T, M> = TMatrix::zeros();
+        let mut k = T::zero();
+        for _ in 0..M {
+            k = k + T::one();
+            m = self.clone() * m + id.clone() * coeffs[coeffs.len() - 1];
+            let mut am = self.clone() * m.clone();
+            coeffs.push(-am.trace() / k);
+        }
+        coeffs
+    }
+
+    //p(a) by horner's method, coefficients from the highest degree down
+    pub fn eval_polynomial(&self, coeffs: &[T]) -> TMatrix<T, M> {
+        let id: TMatrix<T, M> = TMatrix::identity();
+        coeffs.iter().fold(TMatrix::zeros(), |acc, c| {
+            acc * self.clone() + id.clone() * *c
+        })
+    }
+
+    //every matrix is a root of its characteristic polynomial, so this is zero up to rounding
+    pub fn cayley_hamilton_residual(&self) -> TMatrix<T, M> {
+        self.eval_polynomial(&self.characteristic_polynomial())
+    }
+
+    //the residual is compared to |a|^M, the size of the largest term of p(a)
+    pub fn satisfies_cayley_hamilton(&self, tolerance: T) -> bool {
+        let scale = self.norm_max().powi(M as i32);
+        let scale = if scale > T::one() { scale } else { T::one() };
+        self.cayley_hamilton_residual().norm_max() <= tolerance * scale
+    }
+}
+
+#[cfg(test)]
+mod characteristic {
+    use super::*;
+    use crate::base_structs::matrix::Matrix;
+
+    #[test]
+    fn test_horner() {
+        assert_eq!(horner(&[1., -3., 2.], 1.), 0.);
+        assert_eq!(horner(&[1., -3., 2.], 4.), 6.);
+        assert_eq!(horner(&[2., 0., 0., 1.], 2.), 17.);
+        assert_eq!(horner::<f32>(&[], 5.), 0.);
+    }
+
+    #[test]
+    fn test_characteristic_polynomial() {
+        //x^2 - trace x + det
+        let a = Matrix::from([[1., 2.], [3., 4.]]);
+        assert_eq!(a.characteristic_polynomial(), vec![1., -5., -2.]);
+        //triangular: the product of (x - a_ii)
+        let a = Matrix::from([[2., 1., 5.], [0., 3., 7.], [0., 0., -1.]]);
+        assert_eq!(a.characteristic_polynomial(), vec![1., -4., 1., 6.]);
+        //the constant term is (-1)^n det, the next one is -trace
+        let mut a = Matrix::from([[2., -1., 0.], [1., 3., 4.], [0., 5., -2.]]);
+        let p = a.characteristic_polynomial();
+        assert_eq!(p[1], -a.trace());
+        assert!((p[3] + a.determinant()).abs() < 1e-4);
+        let id: TMatrix<f32, 2> = TMatrix::identity();
+        assert_eq!(id.characteristic_polynomial(), vec![1., -2., 1.]);
+    }
+
+    #[test]
+    fn test_eval_polynomial() {
+        let a = Matrix::from([[1., 1.], [0., 2.]]);
+        //a^2 - a + 2i
+        assert_eq!(
+            a.eval_polynomial(&[1., -1., 2.]),
+            Matrix::from([[2., 2.], [0., 4.]])
+        );
+        assert_eq!(a.eval_polynomial(&[3.]), TMatrix::identity() * 3.);
+    }
+
+    #[test]
+    fn test_cayley_hamilton() {
+        let a = Matrix::from([[1., 2.], [3., 4.]]);
+        assert_eq!(a.cayley_hamilton_residual(), TMatrix::zeros());
+        let a = Matrix::from([
+            [2., -1., 0., 3.],
+            [1., 3., 4., -2.],
+            [0., 5., -2., 1.],
+            [1., 0., 1., 1.],
+        ]);
+        assert!(a.satisfies_cayley_hamilton(1e-5));
+        //a polynomial that isn't the characteristic one
+        let mut p = a.characteristic_polynomial();
+        p[4] += 1.;
+        assert!(a.eval_polynomial(&p).norm_max() > 0.5);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::base_structs::dmatrix::DMatrix;
+use crate::base_structs::dvector::DVector;
+use crate::base_structs::matrix::Matrix;
+use crate::base_structs::vector::Vector;
+use crate::num_traits::scalar::Scalar;
+
+impl<T: Scalar, const N: usize> Vector<T, N> {
+    //u v^T, entry (i, j) is u_i v_j
+    pub fn outer<const K: usize>(&self, v: &Vector<T, K>) -> Matrix<T, N, K> {
+        Matrix::from_fn(|i, j| self[i] * v[j])
+    }
+}
+
+impl<T: Scalar> DVector<T> {
+    pub fn outer(&self, v: &DVector<T>) -> DMatrix<T> {
+        DMatrix::from_fn(self.len(), v.len(), |i, j| self[i] * v[j])
+    }
+}
+
+impl<T: Scalar> DMatrix<T> {
+    //block matrix with blocks self[i][j] * other, of shape (m p) x (n q)
+    pub fn kronecker(&self, other: &DMatrix<T>) -> DMatrix<T> {
+        let (p, q) = other.shape();
+        DMatrix::from_fn(self.nrows() * p, self.ncols() * q, |r, c| {
+            self[(r / p, c / q)] * other[(r % p, c % q)]
+        })
+    }
+
+    //stacks the columns on top of each other, vec(A X B) = (B^T kron A) vec(X)
+    pub fn vectorize(&self) -> DVector<T> {
+        let rows = self.nrows();
+        DVector::from_fn(rows * self.ncols(), |k| self[(k % rows, k / rows)])
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize> Matrix<T, M, N> {
+    //the output size can't be computed from M, N, P and Q on stable rust,
+    //so it is given by the caller, usually through the type of the result
+    pub fn kronecker<const P: usize, const Q: usize, const R: usize, const S: usize>(
+        &self,
+        other: &Matrix<T, P, Q>,
+    ) -> Result<Matrix<T, R, S>, String> {
+        if R != M * P {
+            return Err(format!("Expected {} rows but got {}", M * P, R));
+        }
+        if S != N * Q {
+            return Err(format!("Expected {} columns but got {}", N * Q, S));
+        }
+        let (a, b) = (self.as_vec(), other.as_vec());
+        Ok(Matrix::from_fn(|r, c| a[r / P][c / Q] * b[r % P][c % Q]))
+    }
+
+    pub fn vectorize<const K: usize>(&self) -> Result<Vector<T, K>, String> {
+        if K != M * N {
+            return Err(format!("Expected {} elements but got {}", M * N, K));
+        }
+        let d = self.as_vec();
+        Ok(Vector::from_fn(|k| d[k % M][k / M]))
+    }
+}
+
+#[cfg(test)]
+mod tensor_products {
+    use super::*;
+    use crate::base_structs::matrix::TMatrix;
+
+    #[test]
+    fn test_outer() {
+        let u = Vector::from([1., 2.]);
+        let v = Vector::from([3., 4., 5.]);
+        assert_eq!(u.outer(&v), Matrix::from([[3., 4., 5.], [6., 8., 10.]]));
+        //rank one, so multiplying by w gives u scaled by v . w
+        let (v2, w) = (Vector::from([3., 4.]), Vector::from([1., -1.]));
+        assert_eq!(u.outer(&v2).mul_vec(&w), u.clone() * v2.dot(&w));
+        let d = DVector::from(u).outer(&DVector::from(v));
+        assert_eq!(d.as_vec(), vec![vec![3., 4., 5.], vec![6., 8., 10.]]);
+    }
+
+    #[test]
+    fn test_kronecker() {
+        let a = Matrix::from([[1., 2.], [3., 4.]]);
+        let b = Matrix::from([[0., 5.], [6., 7.]]);
+        let k: Matrix<f32, 4, 4> = a.kronecker(&b).unwrap();
+        assert_eq!(
+            k,
+            Matrix::from([
+                [0., 5., 0., 10.],
+                [6., 7., 12., 14.],
+                [0., 15., 0., 20.],
+                [18., 21., 24., 28.],
+            ])
+        );
+        assert_eq!(
+            DMatrix::from(a.clone()).kronecker(&DMatrix::from(b.clone())),
+            DMatrix::from(k)
+        );
+        let i: TMatrix<f32, 2> = TMatrix::identity();
+        let k: Matrix<f32, 4, 4> = i.kronecker(&b).unwrap();
+        assert_eq!(k.as_vec()[2], vec![0., 0., 0., 5.]);
+        let bad: Result<Matrix<f32, 4, 3>, String> = a.kronecker(&b);
+        assert_eq!(bad, Err(String::from("Expected 4 columns but got 3")));
+    }
+
+    #[test]
+    fn test_vectorize() {
+        let a = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        let v: Vector<f32, 6> = a.vectorize().unwrap();
+        assert_eq!(v, Vector::from([1., 4., 2., 5., 3., 6.]));
+        assert_eq!(DMatrix::from(a.clone()).vectorize().as_vec(), v.as_vec());
+        assert!(a.vectorize::<5>().is_err());
+    }
+
+    #[test]
+    fn test_sylvester_identity() {
+        //vec(A X B) = (B^T kron A) vec(X)
+        let a = Matrix::from([[1., 2.], [0., -1.]]);
+        let x = Matrix::from([[3., 1.], [2., 4.]]);
+        let mut b = Matrix::from([[2., 0.], [1., 5.]]);
+        let lhs: Vector<f32, 4> = (a.clone() * x.clone() * b.clone()).vectorize().unwrap();
+        let mut k: Matrix<f32, 4, 4> = b.transpose().kronecker(&a).unwrap();
+        let rhs = k.mul_vec(&x.vectorize().unwrap());
+        assert_eq!(lhs, rhs);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::base_structs::vector::{TVector2, Vector};
+use crate::num_traits::real::Real;
+use crate::num_traits::scalar::Scalar;
+
+impl<T: Scalar, const N: usize> Vector<T, N> {
+    //component of self along v, v must not be zero
+    pub fn project_onto(&self, v: &Vector<T, N>) -> Vector<T, N> {
+        v.clone() * (self.dot(v) / v.dot(v))
+    }
+
+    //component of self perpendicular to v, self = projection + rejection
+    pub fn reject_from(&self, v: &Vector<T, N>) -> Vector<T, N> {
+        self.clone() - self.project_onto(v)
+    }
+
+    //mirror image across the hyperplane with the given normal, the normal need not be unit
+    pub fn reflect(&self, normal: &Vector<T, N>) -> Vector<T, N> {
+        let two = T::one() + T::one();
+        self.clone() - self.project_onto(normal) * two
+    }
+
+    //snell's law for a unit incident direction and a unit normal facing against it,
+    //eta is the ratio of refractive indices (from / to), None on total internal reflection
+    pub fn refract(&self, normal: &Vector<T, N>, eta: T) -> Option<Vector<T, N>> {
+        let cos_i = -normal.dot(self);
+        let k = T::one() - eta * eta * (T::one() - cos_i * cos_i);
+        if k < T::zero() {
+            return None;
+        }
+        Some(self.clone() * eta + normal.clone() * (eta * cos_i - k.sqrt()))
+    }
+
+    //same as ex05's angle_cos but stays in T
+    pub fn cos_angle(&self, v: &Vector<T, N>) -> T {
+        self.dot(v) / (self.abs() * v.abs())
+    }
+}
+
+impl<T: Real, const N: usize> Vector<T, N> {
+    //unsigned angle in [0, pi], in radians
+    //kahan's formula, accurate for nearly parallel vectors where acos of the cosine is not
+    pub fn angle(&self, v: &Vector<T, N>) -> T {
+        let a = self.clone() * v.abs();
+        let b = v.clone() * self.abs();
+        let two = T::one() + T::one();
+        two * (a.clone() - b.clone()).abs().atan2((a + b).abs())
+    }
+}
+
+impl<T: Real> TVector2<T> {
+    //counter-clockwise angle from self to v in (-pi, pi]
+    pub fn signed_angle(&self, v: &TVector2<T>) -> T {
+        let cross = self[0] * v[1] - self[1] * v[0];
+        cross.atan2(self.dot(v))
+    }
+}
+
+#[cfg(test)]
+mod projections {
+    use super::*;
+    use crate::utils::comp::{floats_are_equal, vectors_are_equal};
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    #[test]
+    fn test_project_and_reject() {
+        let u = Vector::from([3., 4., 0.]);
+        let v = Vector::from([2., 0., 0.]);
+        assert_eq!(u.project_onto(&v), Vector::from([3., 0., 0.]));
+        assert_eq!(u.reject_from(&v), Vector::from([0., 4., 0.]));
+        assert_eq!(u.reject_from(&v).dot(&v), 0.);
+        assert_eq!(u.project_onto(&v) + u.reject_from(&v), u);
+    }
+
+    #[test]
+    fn test_reflect() {
+        let d = Vector::from([1., -1.]);
+        assert_eq!(d.reflect(&Vector::from([0., 1.])), Vector::from([1., 1.]));
+        //the normal does not need to be unit length
+        assert_eq!(d.reflect(&Vector::from([0., 5.])), Vector::from([1., 1.]));
+        let d = Vector::from([2., 3., 4.]);
+        assert!(vectors_are_equal(
+            &d.reflect(&Vector::from([1., 1., 0.])),
+            &Vector::from([-3., -2., 4.])
+        ));
+    }
+
+    #[test]
+    fn test_refract() {
+        let n = Vector::from([0., 1.]);
+        //straight through when the indices match or at normal incidence
+        let d = Vector::from([1., -1.]).normalize();
+        assert!(vectors_are_equal(&d.refract(&n, 1.).unwrap(), &d));
+        let down = Vector::from([0., -1.]);
+        assert!(vectors_are_equal(&down.refract(&n, 1.5).unwrap(), &down));
+        //into a denser medium the ray bends toward the normal, sin t = sin i / 1.5
+        let t = d.refract(&n, 1. / 1.5).unwrap();
+        assert!(floats_are_equal(t.abs(), 1.));
+        assert!(floats_are_equal(t[0], d[0] / 1.5));
+        //glass to air past the critical angle
+        assert_eq!(d.refract(&n, 1.5), None);
+    }
+
+    #[test]
+    fn test_angles() {
+        let x = Vector::from([1., 0.]);
+        let y = Vector::from([0., 3.]);
+        assert!(floats_are_equal(x.angle(&y), FRAC_PI_2));
+        assert!(floats_are_equal(x.angle(&Vector::from([-2., 0.])), PI));
+        assert!(floats_are_equal(
+            x.cos_angle(&Vector::from([1., 1.])),
+            FRAC_PI_4.cos()
+        ));
+        assert!(floats_are_equal(x.signed_angle(&y), FRAC_PI_2));
+        assert!(floats_are_equal(y.signed_angle(&x), -FRAC_PI_2));
+        assert!(floats_are_equal(
+            x.signed_angle(&Vector::from([-1., -1.])),
+            -3. * FRAC_PI_4
+        ));
+        //acos of the cosine rounds this to zero
+        let tiny = Vector::from([1., 1e-4, 0.]);
+        assert!((Vector::from([1., 0., 0.]).angle(&tiny) - 1e-4).abs() < 1e-8);
+    }
+}
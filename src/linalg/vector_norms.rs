@@ -0,0 +1,256 @@
+use crate::base_structs::matrix::TMatrix;
+use crate::base_structs::vector::Vector;
+use crate::num_traits::real::Real;
+use crate::num_traits::scalar::Scalar;
+use std::cmp::Ordering;
+
+//p-th root of x >= 1 by newton's method, starting above the root so it decreases monotonically
+fn root<T: Real>(x: T, p: u32) -> T {
+    if p == 1 {
+        return x;
+    }
+    if p == 2 {
+        return x.sqrt();
+    }
+    let n = T::from_usize(p as usize);
+    let mut r = x;
+    loop {
+        let next = ((n - T::one()) * r + x / r.powi(p as i32 - 1)) / n;
+        if next >= r {
+            return r;
+        }
+        r = next;
+    }
+}
+
+impl<T: Scalar, const N: usize> Vector<T, N> {
+    fn max_abs(&self) -> T {
+        self.as_slice().iter().fold(
+            T::zero(),
+            |big, x| {
+                if x.abs() > big {
+                    x.abs()
+                } else {
+                    big
+                }
+            },
+        )
+    }
+
+    //the limit of norm_p for large p
+    pub fn norm_max(&self) -> T {
+        self.max_abs()
+    }
+
+    pub fn norm_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    pub fn distance_squared(&self, v: &Vector<T, N>) -> T {
+        (self.clone() - v.clone()).norm_squared()
+    }
+}
+
+//the scaled norms divide and take roots, so they need real T
+impl<T: Real, const N: usize> Vector<T, N> {
+    //(sum |x_i|^p)^(1/p) for whole p, the entries are divided by the largest one first
+    //so that the powers can't overflow
+    pub fn norm_pi(&self, p: u32) -> T {
+        if p == 0 {
+            panic!("Vector: p must be at least 1");
+        }
+        let big = self.max_abs();
+        if big == T::zero() {
+            return T::zero();
+        }
+        let sum = self
+            .as_slice()
+            .iter()
+            .fold(T::zero(), |acc, x| acc + (x.abs() / big).powi(p as i32));
+        big * root(sum, p)
+    }
+
+    //euclidean norm scaled like hypot, abs squares the entries directly
+    //and overflows for entries past the square root of the largest float
+    pub fn norm_euclidean(&self) -> T {
+        self.norm_pi(2)
+    }
+
+    pub fn distance(&self, v: &Vector<T, N>) -> T {
+        (self.clone() - v.clone()).norm_euclidean()
+    }
+
+    //sqrt(d^T w d) for a symmetric positive definite weight matrix w
+    pub fn norm_weighted(&self, w: &TMatrix<T, N>) -> Result<T, String> {
+        let w = w.as_vec();
+        let mut res = T::zero();
+        for (i, row) in w.iter().enumerate() {
+            for (j, item) in row.iter().enumerate() {
+                res = res + self[i] * *item * self[j];
+            }
+        }
+        if res < T::zero() {
+            return Err(String::from("matrix is not positive definite"));
+        }
+        Ok(res.sqrt())
+    }
+
+    //distance to mean in units of the spread of the distribution given by its covariance
+    //solves with the cholesky factor instead of inverting the covariance
+    pub fn mahalanobis(
+        &self,
+        mean: &Vector<T, N>,
+        covariance: &TMatrix<T, N>,
+    ) -> Result<T, String> {
+        let c = covariance.as_vec();
+        let mut l = vec![vec![T::zero(); N]; N];
+        let dot = |a: &[T], b: &[T]| {
+            a.iter()
+                .zip(b.iter())
+                .fold(T::zero(), |acc, (x, y)| acc + *x * *y)
+        };
+        for j in 0..N {
+            let diag = c[j][j] - dot(&l[j][..j], &l[j][..j]);
+            if diag <= T::zero() {
+                return Err(String::from("matrix is not positive definite"));
+            }
+            l[j][j] = diag.sqrt();
+            for i in j + 1..N {
+                l[i][j] = (c[i][j] - dot(&l[i][..j], &l[j][..j])) / l[j][j];
+            }
+        }
+        //l y = x - mean, then the distance is |y|
+        let d = self.clone() - mean.clone();
+        let mut y = vec![T::zero(); N];
+        for i in 0..N {
+            y[i] = (d[i] - dot(&l[i][..i], &y[..i])) / l[i][i];
+        }
+        Ok(Vector::<T, N>::from_fn(|i| y[i]).norm_euclidean())
+    }
+
+    //same as norm_pi for any real p >= 1, infinity gives norm_max
+    pub fn norm_p(&self, p: T) -> T {
+        //nan compares as none and is rejected too
+        if matches!(p.partial_cmp(&T::one()), None | Some(Ordering::Less)) {
+            panic!("Vector: p must be at least 1");
+        }
+        let big = self.max_abs();
+        if big == T::zero() {
+            return T::zero();
+        }
+        let sum = self
+            .as_slice()
+            .iter()
+            .fold(T::zero(), |acc, x| acc + (x.abs() / big).powf(p));
+        big * sum.powf(T::one() / p)
+    }
+
+    pub fn distance_p(&self, v: &Vector<T, N>, p: T) -> T {
+        (self.clone() - v.clone()).norm_p(p)
+    }
+}
+
+#[cfg(test)]
+mod minkowski {
+    use super::*;
+    use crate::base_structs::matrix::Matrix;
+    use crate::utils::comp::floats_are_equal;
+
+    #[test]
+    fn test_norm_pi() {
+        let v = Vector::from([3., -4.]);
+        assert_eq!(v.norm_pi(1), 7.);
+        assert_eq!(v.norm_pi(2), 5.);
+        assert!(floats_are_equal(v.norm_pi(3), 91_f32.powf(1. / 3.)));
+        assert_eq!(v.norm_max(), 4.);
+        //norm_pi tends to norm_max
+        assert!((v.norm_pi(40) - 4.).abs() < 1e-3);
+        assert_eq!(Vector::from([0., 0.]).norm_pi(3), 0.);
+        //matches ex04
+        let mut u = Vector::from([1., -2., 3.]);
+        assert_eq!(u.norm_pi(1), u.norm_1());
+        assert!(floats_are_equal(u.norm_pi(2), u.norm()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector: p must be at least 1")]
+    fn test_norm_zero_pi() {
+        Vector::from([1., 2.]).norm_pi(0);
+    }
+
+    #[test]
+    fn test_norm_p() {
+        let v = Vector::from([3., -4.]);
+        assert!(floats_are_equal(v.norm_p(1.), v.norm_pi(1)));
+        assert!(floats_are_equal(v.norm_p(2.), 5.));
+        assert!(floats_are_equal(v.norm_p(3.), v.norm_pi(3)));
+        assert!(floats_are_equal(
+            v.norm_p(1.5),
+            (3_f32.powf(1.5) + 8.).powf(1. / 1.5)
+        ));
+        assert_eq!(v.norm_p(f32::INFINITY), 4.);
+        assert_eq!(Vector::from([0., 0.]).norm_p(2.5), 0.);
+        let v = Vector::from([3e30, 4e30]);
+        assert!(floats_are_equal(v.norm_p(2.) / 1e30, 5.));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector: p must be at least 1")]
+    fn test_norm_p_below_one() {
+        Vector::from([1., 2.]).norm_p(0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector: p must be at least 1")]
+    fn test_norm_p_nan() {
+        Vector::from([1., 2.]).norm_p(f32::NAN);
+    }
+
+    #[test]
+    fn test_no_overflow() {
+        let v = Vector::from([3e30, 4e30]);
+        assert!(v.abs().is_infinite());
+        assert!(floats_are_equal(v.norm_euclidean() / 1e30, 5.));
+        let v = Vector::from([3e-30, 4e-30]);
+        assert_eq!(v.abs(), 0.);
+        assert!(floats_are_equal(v.norm_euclidean() * 1e30, 5.));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Vector::from([1., 2., 3.]);
+        let b = Vector::from([4., 6., 3.]);
+        assert_eq!(a.norm_squared(), 14.);
+        assert_eq!(a.distance(&b), 5.);
+        assert_eq!(a.distance_squared(&b), 25.);
+        assert_eq!(a.distance_p(&b, 1.), 7.);
+        assert!(floats_are_equal(a.distance_p(&b, 2.), a.distance(&b)));
+    }
+
+    #[test]
+    fn test_weighted_and_mahalanobis() {
+        let cov = Matrix::from([[4., 0.], [0., 1.]]);
+        let mean = Vector::from([1., 1.]);
+        //two standard deviations along x
+        let x = Vector::from([5., 1.]);
+        assert!(floats_are_equal(x.mahalanobis(&mean, &cov).unwrap(), 2.));
+        let w = Matrix::from([[0.25, 0.], [0., 1.]]);
+        assert!(floats_are_equal(
+            (x.clone() - mean.clone()).norm_weighted(&w).unwrap(),
+            2.
+        ));
+        //correlated: d^T cov^-1 d computed through the inverse
+        let cov = Matrix::from([[2., 1.], [1., 2.]]);
+        let d = Vector::from([1., -1.]);
+        let mut inv = cov.clone();
+        let expected = d.norm_weighted(&inv.inverse().unwrap()).unwrap();
+        let got = d.mahalanobis(&Vector::zeros(), &cov).unwrap();
+        assert!(floats_are_equal(got, expected));
+        assert!(floats_are_equal(got, 2_f32.sqrt()));
+        let bad = Matrix::from([[1., 2.], [2., 1.]]);
+        assert_eq!(
+            d.mahalanobis(&Vector::zeros(), &bad),
+            Err(String::from("matrix is not positive definite"))
+        );
+    }
+}
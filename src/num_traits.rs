@@ -1 +1,5 @@
-pub mod scalar;
\ No newline at end of file
+pub mod integer;
+pub mod modp;
+pub mod real;
+pub mod ring;
+pub mod scalar;
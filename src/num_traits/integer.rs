@@ -0,0 +1,43 @@
+use num::{BigInt, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, Zero};
+
+//exact integers with overflow checked arithmetic, for the algorithms that divide
+//only where the quotient is known to be exact (bareiss) or where rounding is part
+//of the algorithm (hermite and smith normal forms)
+//Clone instead of Copy so that num::BigInt qualifies, which is also why BigInt can't
+//be a Ring: the matrix arithmetic takes its elements by value and needs Copy
+pub trait Integer:
+    Clone + Ord + Zero + One + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv
+{
+}
+
+impl Integer for i64 {}
+impl Integer for i128 {}
+impl Integer for BigInt {}
+
+#[cfg(test)]
+mod checked_integers {
+    use super::*;
+
+    fn add<T: Integer>(a: T, b: T) -> Option<T> {
+        a.checked_add(&b)
+    }
+
+    fn div<T: Integer>(a: T, b: T) -> Option<T> {
+        a.checked_div(&b)
+    }
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(CheckedMul::checked_mul(&i64::MAX, &2), None);
+        assert_eq!(add(3_i64, 4), Some(7));
+        assert_eq!(CheckedSub::checked_sub(&i128::MIN, &1), None);
+        assert_eq!(div(7_i64, 0), None);
+        assert_eq!(div(-7_i64, 2), Some(-3));
+        assert_eq!(div(i64::MIN, -1), None);
+        let big = BigInt::from(i64::MAX);
+        assert_eq!(
+            add(big.clone(), big),
+            Some(BigInt::from(2 * i64::MAX as i128))
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use crate::num_traits::ring::Ring;
+use crate::num_traits::scalar::Scalar;
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+//integers modulo P, P has to be prime so that every non-zero value has an inverse,
+//ModP<0> or ModP<15> fails to compile as soon as a value is created
+//the order is the one of the representatives in [0, P), it only exists to
+//satisfy Scalar and means nothing algebraically
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+pub struct ModP<const P: u64> {
+    value: u64,
+}
+
+//deterministic miller-rabin, the first twelve primes as bases cover every u64
+const fn is_prime(p: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if p < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < BASES.len() {
+        if p.is_multiple_of(BASES[i]) {
+            return p == BASES[i];
+        }
+        i += 1;
+    }
+    //p - 1 = d 2^s with d odd
+    let (mut d, mut s) = (p - 1, 0);
+    while d % 2 == 0 {
+        d /= 2;
+        s += 1;
+    }
+    let m = p as u128;
+    let mut i = 0;
+    'bases: while i < BASES.len() {
+        let (mut x, mut base, mut e) = (1_u128, BASES[i] as u128, d);
+        i += 1;
+        while e > 0 {
+            if e & 1 == 1 {
+                x = x * base % m;
+            }
+            base = base * base % m;
+            e >>= 1;
+        }
+        if x == 1 || x == m - 1 {
+            continue;
+        }
+        let mut r = 1;
+        while r < s {
+            x = x * x % m;
+            if x == m - 1 {
+                continue 'bases;
+            }
+            r += 1;
+        }
+        return false;
+    }
+    true
+}
+
+impl<const P: u64> ModP<P> {
+    pub fn new(v: u64) -> ModP<P> {
+        const { assert!(is_prime(P), "ModP: P must be prime") };
+        ModP { value: v % P }
+    }
+
+    fn mul_mod(a: u64, b: u64) -> ModP<P> {
+        ModP::new(((a as u128 * b as u128) % P as u128) as u64)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn pow(&self, mut e: u64) -> ModP<P> {
+        let mut base = *self;
+        let mut res = ModP::new(1);
+        while e > 0 {
+            if e & 1 == 1 {
+                res = res * base;
+            }
+            base = base * base;
+            e >>= 1;
+        }
+        res
+    }
+
+    //fermat: a^(p-1) = 1, so a^(p-2) is the inverse
+    pub fn inverse(&self) -> Option<ModP<P>> {
+        if self.value == 0 {
+            None
+        } else {
+            Some(self.pow(P - 2))
+        }
+    }
+
+    //euler's criterion, zero counts as a square
+    pub fn is_square(&self) -> bool {
+        self.value == 0 || P == 2 || self.pow((P - 1) / 2).value == 1
+    }
+
+    //tonelli-shanks, the smaller of the two roots
+    pub fn sqrt_mod(&self) -> Option<ModP<P>> {
+        if !self.is_square() {
+            return None;
+        }
+        if self.value == 0 || P == 2 {
+            return Some(*self);
+        }
+        //p - 1 = q 2^s with q odd
+        let (mut q, mut s) = (P - 1, 0);
+        while q % 2 == 0 {
+            q /= 2;
+            s += 1;
+        }
+        let mut z = ModP::new(2);
+        while z.is_square() {
+            z = z + ModP::new(1);
+        }
+        let mut c = z.pow(q);
+        let mut t = self.pow(q);
+        let mut r = self.pow(q.div_ceil(2));
+        let mut m = s;
+        while t.value != 1 {
+            //smallest i with t^(2^i) = 1
+            let mut i = 0;
+            let mut t2 = t;
+            while t2.value != 1 {
+                t2 = t2 * t2;
+                i += 1;
+            }
+            let b = c.pow(1 << (m - i - 1));
+            m = i;
+            c = b * b;
+            t = t * c;
+            r = r * b;
+        }
+        let other = -r;
+        Some(if other.value < r.value { other } else { r })
+    }
+}
+
+impl<const P: u64> From<i64> for ModP<P> {
+    fn from(v: i64) -> ModP<P> {
+        ModP::new((v as i128).rem_euclid(P as i128) as u64)
+    }
+}
+
+impl<const P: u64> Add for ModP<P> {
+    type Output = ModP<P>;
+
+    fn add(self, rhs: ModP<P>) -> ModP<P> {
+        ModP::new(((self.value as u128 + rhs.value as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for ModP<P> {
+    type Output = ModP<P>;
+
+    fn sub(self, rhs: ModP<P>) -> ModP<P> {
+        self + (-rhs)
+    }
+}
+
+impl<const P: u64> Neg for ModP<P> {
+    type Output = ModP<P>;
+
+    fn neg(self) -> ModP<P> {
+        ModP::new(P - self.value)
+    }
+}
+
+impl<const P: u64> Mul for ModP<P> {
+    type Output = ModP<P>;
+
+    fn mul(self, rhs: ModP<P>) -> ModP<P> {
+        ModP::mul_mod(self.value, rhs.value)
+    }
+}
+
+impl<const P: u64> Div for ModP<P> {
+    type Output = ModP<P>;
+
+    fn div(self, rhs: ModP<P>) -> ModP<P> {
+        match rhs.inverse() {
+            Some(inv) => ModP::mul_mod(self.value, inv.value),
+            None => panic!("ModP: division by zero"),
+        }
+    }
+}
+
+impl<const P: u64> Display for ModP<P> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<const P: u64> Ring for ModP<P> {
+    fn zero() -> Self {
+        ModP::new(0)
+    }
+    fn one() -> Self {
+        ModP::new(1)
+    }
+    fn from_usize(n: usize) -> Self {
+        ModP::new(n as u64)
+    }
+}
+
+impl<const P: u64> Scalar for ModP<P> {
+    fn sqrt(&self) -> Self {
+        match self.sqrt_mod() {
+            Some(r) => r,
+            None => panic!("ModP: {} is not a square modulo {}", self.value, P),
+        }
+    }
+    fn powi(&self, i: i32) -> Self {
+        let p = self.pow(i.unsigned_abs() as u64);
+        if i < 0 {
+            ModP::one() / p
+        } else {
+            p
+        }
+    }
+}
+
+#[cfg(test)]
+mod prime_field {
+    use super::*;
+    use crate::base_structs::dmatrix::DMatrix;
+    use crate::base_structs::matrix::{Matrix, TMatrix};
+
+    type F7 = ModP<7>;
+    type F29 = ModP<29>;
+
+    fn m<const P: u64, const N: usize>(rows: [[i64; N]; N]) -> TMatrix<ModP<P>, N> {
+        Matrix::from_fn(|r, c| ModP::from(rows[r][c]))
+    }
+
+    #[test]
+    fn test_is_prime() {
+        let small: Vec<u64> = (0..40).filter(|p| is_prime(*p)).collect();
+        assert_eq!(small, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37]);
+        assert!(is_prime(18446744073709551557));
+        assert!(!is_prime(u64::MAX));
+        //strong pseudoprime to the bases 2 through 11
+        assert!(!is_prime(2152302898747));
+        //carmichael number
+        assert!(!is_prime(561));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (F7::new(5), F7::new(4));
+        assert_eq!(a + b, F7::new(2));
+        assert_eq!(a - b, F7::new(1));
+        assert_eq!(b - a, F7::new(6));
+        assert_eq!(a * b, F7::new(6));
+        assert_eq!(a / b * b, a);
+        assert_eq!(-a, F7::new(2));
+        assert_eq!(F7::from(-1), F7::new(6));
+        assert_eq!(a.inverse(), Some(F7::new(3)));
+        assert_eq!(F7::new(0).inverse(), None);
+        assert_eq!(Scalar::powi(&a, -1), F7::new(3));
+        //large modulus does not overflow
+        let big = ModP::<18446744073709551557>::new(u64::MAX - 60);
+        assert_eq!(big * big.inverse().unwrap(), ModP::new(1));
+    }
+
+    #[test]
+    fn test_sqrt() {
+        //29 - 1 = 7 * 4 so tonelli-shanks needs a few rounds
+        for v in 0..29 {
+            let x = F29::new(v);
+            match x.sqrt_mod() {
+                Some(r) => assert_eq!(r * r, x),
+                None => assert!(!x.is_square()),
+            }
+        }
+        assert_eq!(F29::new(6).sqrt_mod(), Some(F29::new(8)));
+        assert_eq!(F29::new(2).sqrt_mod(), None);
+        assert_eq!(ModP::<2>::new(1).sqrt_mod(), Some(ModP::new(1)));
+    }
+
+    #[test]
+    fn test_matrix_operations() {
+        let a: TMatrix<F7, 3> = m([[1, 2, 3], [0, 1, 4], [5, 6, 0]]);
+        //the integer determinant is 1
+        let mut d = a.clone();
+        assert_eq!(d.determinant(), F7::new(1));
+        let mut inv = a.clone();
+        let inv = inv.inverse().unwrap();
+        assert_eq!(a.clone() * inv, TMatrix::identity());
+        //singular modulo 7 but not over the integers
+        let mut s: TMatrix<F7, 2> = m([[1, 2], [3, 13]]);
+        assert_eq!(s.determinant(), F7::new(0));
+        assert_eq!(s.rank(), 1);
+        assert_eq!(s.inverse(), Err(String::from("matrix is singular")));
+        let mut r = s.clone();
+        assert_eq!(r.row_echelon(), m([[1, 2], [0, 0]]));
+        let dm = DMatrix::from(a.clone());
+        assert_eq!(dm.determinant(), F7::new(1));
+        assert_eq!(dm.rank(), 3);
+        assert_eq!(dm.inverse().unwrap() * dm, DMatrix::identity(3));
+    }
+
+    #[test]
+    fn test_hill_cipher() {
+        //encrypt two letters with a key matrix modulo 29 and decrypt with its inverse
+        let key: TMatrix<F29, 2> = m([[3, 3], [2, 5]]);
+        let mut k = key.clone();
+        let plain = crate::base_structs::vector::Vector::from([F29::new(7), F29::new(8)]);
+        let mut enc = key.clone();
+        let cipher = enc.mul_vec(&plain);
+        let mut dec = k.inverse().unwrap();
+        assert_eq!(dec.mul_vec(&cipher), plain);
+    }
+}
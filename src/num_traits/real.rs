@@ -0,0 +1,44 @@
+use crate::num_traits::scalar::Scalar;
+
+//scalars that support trigonometry, needed by rotations and angles
+pub trait Real: Scalar {
+    fn pi() -> Self;
+    fn from_f32(v: f32) -> Self;
+    fn sin(&self) -> Self;
+    fn cos(&self) -> Self;
+    fn tan(&self) -> Self;
+    fn asin(&self) -> Self;
+    fn acos(&self) -> Self;
+    fn atan2(&self, x: Self) -> Self;
+    fn powf(&self, e: Self) -> Self;
+}
+
+impl Real for f32 {
+    fn pi() -> f32 {
+        std::f32::consts::PI
+    }
+    fn from_f32(v: f32) -> f32 {
+        v
+    }
+    fn sin(&self) -> f32 {
+        f32::sin(*self)
+    }
+    fn cos(&self) -> f32 {
+        f32::cos(*self)
+    }
+    fn tan(&self) -> f32 {
+        f32::tan(*self)
+    }
+    fn asin(&self) -> f32 {
+        f32::asin(*self)
+    }
+    fn acos(&self) -> f32 {
+        f32::acos(*self)
+    }
+    fn atan2(&self, x: f32) -> f32 {
+        f32::atan2(*self, x)
+    }
+    fn powf(&self, e: f32) -> f32 {
+        f32::powf(*self, e)
+    }
+}
@@ -0,0 +1,83 @@
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Mul, Neg, Sub};
+
+//addition, subtraction and multiplication without division
+//enough for matrix products, powers and cofactor determinants, which stay exact
+//for integers, everything that divides needs a Scalar instead
+pub trait Ring:
+    Display
+    + Debug
+    + Clone
+    + Copy
+    + Add<Output = Self>
+    + Mul<Output = Self>
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+    + PartialEq
+    + PartialOrd
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    //sizes and counts that show up in formulas
+    fn from_usize(n: usize) -> Self;
+    fn abs(&self) -> Self {
+        if *self < Self::zero() {
+            -*self
+        } else {
+            *self
+        }
+    }
+}
+
+impl Ring for f32 {
+    fn zero() -> Self {
+        0.0_f32
+    }
+    fn one() -> Self {
+        1.0_f32
+    }
+    fn from_usize(n: usize) -> f32 {
+        n as f32
+    }
+    fn abs(&self) -> f32 {
+        f32::abs(*self)
+    }
+}
+
+//integers are a ring but not a Scalar, `/` rounds and would give wrong
+//answers in every algorithm that divides
+macro_rules! integer_ring {
+    ($t:ty) => {
+        impl Ring for $t {
+            fn zero() -> Self {
+                0
+            }
+            fn one() -> Self {
+                1
+            }
+            fn from_usize(n: usize) -> $t {
+                n as $t
+            }
+        }
+    };
+}
+
+integer_ring!(i64);
+integer_ring!(i128);
+
+#[cfg(test)]
+mod ring_elements {
+    use super::*;
+    use crate::base_structs::matrix::{Matrix, TMatrix};
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(<i64 as Ring>::from_usize(7), 7);
+        assert_eq!(Ring::abs(&-7_i128), 7);
+        assert_eq!(<f32 as Ring>::abs(&-0.5), 0.5);
+        //no division involved, so products and cofactor determinants stay exact
+        let mut a: TMatrix<i64, 3> = Matrix::from([[2, 3, 1], [4, 7, 5], [1, 2, 9]]);
+        assert_eq!(a.clone() * TMatrix::identity(), a);
+        assert_eq!(a.determinant(), 14);
+    }
+}
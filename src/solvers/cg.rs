@@ -0,0 +1,176 @@
+use crate::base_structs::dvector::DVector;
+use crate::num_traits::scalar::Scalar;
+use crate::solvers::operator::{LinearOperator, RowAccess};
+use crate::solvers::solution::{prepare, residual, Settings, Solution};
+
+//approximates A^-1 r, a good preconditioner makes cg converge in fewer iterations
+pub trait Preconditioner<T: Scalar> {
+    fn apply(&self, r: &DVector<T>) -> DVector<T>;
+}
+
+struct Identity;
+
+impl<T: Scalar> Preconditioner<T> for Identity {
+    fn apply(&self, r: &DVector<T>) -> DVector<T> {
+        r.clone()
+    }
+}
+
+//divides by the diagonal of A, cheap and effective for diagonally dominant systems
+#[derive(PartialEq, Debug, Clone)]
+pub struct JacobiPreconditioner<T> {
+    inv_diag: DVector<T>,
+}
+
+impl<T: Scalar> JacobiPreconditioner<T> {
+    pub fn new<A: RowAccess<T> + ?Sized>(a: &A) -> Result<JacobiPreconditioner<T>, String> {
+        let d = a.diagonal();
+        let mut inv = DVector::zeros(d.len());
+        for i in 0..d.len() {
+            if d[i] == T::zero() {
+                return Err(String::from("matrix has a zero on the diagonal"));
+            }
+            inv[i] = T::one() / d[i];
+        }
+        Ok(JacobiPreconditioner { inv_diag: inv })
+    }
+}
+
+impl<T: Scalar> Preconditioner<T> for JacobiPreconditioner<T> {
+    fn apply(&self, r: &DVector<T>) -> DVector<T> {
+        DVector::from_fn(r.len(), |i| r[i] * self.inv_diag[i])
+    }
+}
+
+//conjugate gradient for symmetric positive definite systems
+pub fn conjugate_gradient<T: Scalar, A: LinearOperator<T> + ?Sized>(
+    a: &A,
+    b: &DVector<T>,
+    settings: &Settings<T>,
+) -> Result<Solution<T>, String> {
+    preconditioned_cg(a, b, &Identity, settings)
+}
+
+pub fn preconditioned_cg<T, A, P>(
+    a: &A,
+    b: &DVector<T>,
+    m: &P,
+    settings: &Settings<T>,
+) -> Result<Solution<T>, String>
+where
+    T: Scalar,
+    A: LinearOperator<T> + ?Sized,
+    P: Preconditioner<T> + ?Sized,
+{
+    let (mut x, b_norm) = prepare(a, b, settings)?;
+    let mut r = residual(a, b, &x);
+    let mut residuals = vec![r.abs() / b_norm];
+    let mut iterations = 0;
+    let mut converged = residuals[0] <= settings.tolerance;
+    let mut z = m.apply(&r);
+    let mut p = z.clone();
+    let mut rz = r.dot(&z);
+    while !converged && iterations < settings.max_iterations {
+        let ap = a.apply(&p);
+        let pap = p.dot(&ap);
+        if pap <= T::zero() {
+            return Err(String::from("matrix is not positive definite"));
+        }
+        let alpha = rz / pap;
+        x.add(&(p.clone() * alpha));
+        r.sub(&(ap * alpha));
+        iterations += 1;
+        residuals.push(r.abs() / b_norm);
+        converged = residuals[iterations] <= settings.tolerance;
+        z = m.apply(&r);
+        let rz_next = r.dot(&z);
+        p = z.clone() + p * (rz_next / rz);
+        rz = rz_next;
+    }
+    Ok(Solution {
+        x,
+        iterations,
+        residuals,
+        converged,
+    })
+}
+
+#[cfg(test)]
+mod conjugate_gradient {
+    use super::*;
+    use crate::base_structs::dmatrix::DMatrix;
+    use crate::base_structs::matrix::Matrix;
+    use crate::base_structs::sparse::CooMatrix;
+
+    fn assert_close(a: &DVector<f32>, b: &DVector<f32>) {
+        for i in 0..b.len() {
+            assert!((a[i] - b[i]).abs() < 1e-4, "{} vs {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_cg_dense() {
+        let a = Matrix::from([[4., 1.], [1., 3.]]);
+        let b = DVector::from(vec![1., 2.]);
+        let sol = conjugate_gradient(&a, &b, &Settings::new(1e-6, 10)).unwrap();
+        assert!(sol.converged);
+        assert!(sol.iterations <= 2);
+        assert_close(&sol.x, &DVector::from(vec![1. / 11., 7. / 11.]));
+        assert_eq!(sol.residuals.len(), sol.iterations + 1);
+    }
+
+    #[test]
+    fn test_pcg_sparse_laplacian() {
+        let n = 50;
+        let mut coo = CooMatrix::new(n, n);
+        for i in 0..n {
+            coo.push(i, i, 2. + i as f32);
+            if i > 0 {
+                coo.push(i, i - 1, -1.);
+                coo.push(i - 1, i, -1.);
+            }
+        }
+        let a = coo.to_csr();
+        let expected = DVector::from_fn(n, |i| (i % 7) as f32 - 3.);
+        let b = a.apply(&expected);
+        let settings = Settings::new(1e-6, 200);
+        let plain = conjugate_gradient(&a, &b, &settings).unwrap();
+        let m = JacobiPreconditioner::new(&a).unwrap();
+        let pre = preconditioned_cg(&a, &b, &m, &settings).unwrap();
+        assert!(plain.converged && pre.converged);
+        assert!(pre.iterations <= plain.iterations);
+        for i in 0..n {
+            assert!((pre.x[i] - expected[i]).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_cg_initial_guess_is_solution() {
+        let a = DMatrix::<f32>::identity(3);
+        let b = DVector::from(vec![1., 2., 3.]);
+        let settings = Settings::new(1e-6, 10).with_initial_guess(b.clone());
+        let sol = conjugate_gradient(&a, &b, &settings).unwrap();
+        assert_eq!(sol.iterations, 0);
+        assert_eq!(sol.x, b);
+    }
+
+    #[test]
+    fn test_cg_errors() {
+        let a = Matrix::from([[1., 0.], [0., -1.]]);
+        let b = DVector::from(vec![1., 1.]);
+        let res = conjugate_gradient(&a, &b, &Settings::new(1e-6, 10));
+        assert_eq!(res, Err(String::from("matrix is not positive definite")));
+        let res = conjugate_gradient(&a, &DVector::from(vec![1.]), &Settings::new(1e-6, 10));
+        assert_eq!(
+            res,
+            Err(String::from(
+                "Expected right hand side of length 2 but got 1"
+            ))
+        );
+        let a = Matrix::from([[1., 0.], [0., 0.]]);
+        assert_eq!(
+            JacobiPreconditioner::new(&a),
+            Err(String::from("matrix has a zero on the diagonal"))
+        );
+    }
+}
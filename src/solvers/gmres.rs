@@ -0,0 +1,166 @@
+use crate::base_structs::dvector::DVector;
+use crate::num_traits::scalar::Scalar;
+use crate::solvers::operator::LinearOperator;
+use crate::solvers::solution::{prepare, residual, Settings, Solution};
+
+//rotation that zeroes b in the pair (a, b), returns (cos, sin, length)
+fn givens<T: Scalar>(a: T, b: T) -> (T, T, T) {
+    let r = (a * a + b * b).sqrt();
+    if r == T::zero() {
+        return (T::one(), T::zero(), T::zero());
+    }
+    (a / r, b / r, r)
+}
+
+//restarted gmres(m) for general square systems
+//builds an orthonormal krylov basis of at most `restart` vectors with arnoldi,
+//then minimizes the residual over it and starts again from the new iterate
+pub fn gmres<T: Scalar, A: LinearOperator<T> + ?Sized>(
+    a: &A,
+    b: &DVector<T>,
+    restart: usize,
+    settings: &Settings<T>,
+) -> Result<Solution<T>, String> {
+    if restart == 0 {
+        return Err(String::from("restart must be at least 1"));
+    }
+    let (mut x, b_norm) = prepare(a, b, settings)?;
+    let mut r = residual(a, b, &x);
+    let mut beta = r.abs();
+    let mut residuals = vec![beta / b_norm];
+    let mut iterations = 0;
+    let mut converged = residuals[0] <= settings.tolerance;
+
+    while !converged && iterations < settings.max_iterations {
+        let mut v = vec![r / beta];
+        let mut h: Vec<Vec<T>> = Vec::new();
+        let mut rot: Vec<(T, T)> = Vec::new();
+        let mut g = vec![beta];
+        let mut k = 0;
+        while k < restart && iterations < settings.max_iterations {
+            //arnoldi step with modified gram-schmidt
+            let mut w = a.apply(&v[k]);
+            let mut col = vec![T::zero(); k + 2];
+            for (i, vi) in v.iter().enumerate() {
+                col[i] = w.dot(vi);
+                w.sub(&(vi.clone() * col[i]));
+            }
+            let w_norm = w.abs();
+            col[k + 1] = w_norm;
+            //apply the previous rotations to the new column, then eliminate its last entry
+            for (i, &(c, s)) in rot.iter().enumerate() {
+                let (p, q) = (col[i], col[i + 1]);
+                col[i] = c * p + s * q;
+                col[i + 1] = c * q - s * p;
+            }
+            let (c, s, len) = givens(col[k], col[k + 1]);
+            col[k] = len;
+            col[k + 1] = T::zero();
+            rot.push((c, s));
+            g.push(-s * g[k]);
+            g[k] = c * g[k];
+            h.push(col);
+            k += 1;
+            iterations += 1;
+            residuals.push(g[k].abs() / b_norm);
+            converged = residuals[iterations] <= settings.tolerance;
+            //a zero w means the krylov space is invariant and the solution is exact
+            if converged || w_norm == T::zero() {
+                break;
+            }
+            v.push(w / w_norm);
+        }
+        //back substitution on the k x k upper triangular system h y = g
+        let mut y = vec![T::zero(); k];
+        for i in (0..k).rev() {
+            let mut sum = g[i];
+            for (j, &yj) in y.iter().enumerate().skip(i + 1) {
+                sum = sum - h[j][i] * yj;
+            }
+            if h[i][i] == T::zero() {
+                return Err(String::from("matrix is singular"));
+            }
+            y[i] = sum / h[i][i];
+        }
+        for (vi, &yi) in v.iter().zip(y.iter()) {
+            x.add(&(vi.clone() * yi));
+        }
+        r = residual(a, b, &x);
+        beta = r.abs();
+        if beta == T::zero() {
+            converged = true;
+        }
+    }
+    Ok(Solution {
+        x,
+        iterations,
+        residuals,
+        converged,
+    })
+}
+
+#[cfg(test)]
+mod krylov {
+    use super::*;
+    use crate::base_structs::dmatrix::DMatrix;
+    use crate::base_structs::matrix::Matrix;
+    use crate::base_structs::sparse::CooMatrix;
+
+    fn assert_close(a: &DVector<f32>, b: &DVector<f32>) {
+        for i in 0..b.len() {
+            assert!((a[i] - b[i]).abs() < 1e-4, "{} vs {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_gmres_nonsymmetric() {
+        let a = Matrix::from([[3., 2., -1.], [2., -2., 4.], [-1., 0.5, -1.]]);
+        let x = DVector::from(vec![1., -2., -2.]);
+        let b = a.apply(&x);
+        let sol = gmres(&a, &b, 3, &Settings::new(1e-6, 10)).unwrap();
+        assert!(sol.converged);
+        assert!(sol.iterations <= 3);
+        assert_close(&sol.x, &x);
+    }
+
+    #[test]
+    fn test_gmres_restarted_sparse() {
+        let n = 40;
+        let mut coo = CooMatrix::new(n, n);
+        for i in 0..n {
+            coo.push(i, i, 4.);
+            if i + 1 < n {
+                coo.push(i, i + 1, -1.5);
+            }
+            if i > 0 {
+                coo.push(i, i - 1, -0.5);
+            }
+        }
+        let a = coo.to_csr();
+        let x = DVector::from_fn(n, |i| ((i * 3) % 5) as f32);
+        let b = a.apply(&x);
+        let sol = gmres(&a, &b, 5, &Settings::new(1e-6, 200)).unwrap();
+        assert!(sol.converged);
+        for i in 0..n {
+            assert!((sol.x[i] - x[i]).abs() < 1e-4);
+        }
+        for w in sol.residuals.windows(2) {
+            assert!(w[1] <= w[0] * 1.0001);
+        }
+    }
+
+    #[test]
+    fn test_gmres_errors() {
+        let a = DMatrix::<f32>::identity(2);
+        let b = DVector::from(vec![1., 1.]);
+        assert_eq!(
+            gmres(&a, &b, 0, &Settings::new(1e-6, 10)),
+            Err(String::from("restart must be at least 1"))
+        );
+        let a = DMatrix::<f32>::zeros(2, 3);
+        assert_eq!(
+            gmres(&a, &b, 2, &Settings::new(1e-6, 10)),
+            Err(String::from("matrix is not square"))
+        );
+    }
+}
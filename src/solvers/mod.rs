@@ -0,0 +1,5 @@
+pub mod cg;
+pub mod gmres;
+pub mod operator;
+pub mod solution;
+pub mod stationary;
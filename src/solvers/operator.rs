@@ -0,0 +1,201 @@
+use crate::base_structs::dmatrix::DMatrix;
+use crate::base_structs::dvector::DVector;
+use crate::base_structs::matrix::Matrix;
+use crate::base_structs::sparse::{CscMatrix, CsrMatrix};
+use crate::num_traits::scalar::Scalar;
+
+//anything that can compute A * x, which is all krylov methods need
+pub trait LinearOperator<T: Scalar> {
+    fn nrows(&self) -> usize;
+    fn ncols(&self) -> usize;
+    fn apply(&self, x: &DVector<T>) -> DVector<T>;
+}
+
+//operators that can also list the stored entries of a row,
+//needed by jacobi, gauss-seidel and the jacobi preconditioner
+pub trait RowAccess<T: Scalar>: LinearOperator<T> {
+    fn row_entries(&self, i: usize) -> Vec<(usize, T)>;
+
+    //every row at once, the solvers call this a single time before sweeping
+    fn all_row_entries(&self) -> Vec<Vec<(usize, T)>> {
+        (0..self.nrows()).map(|i| self.row_entries(i)).collect()
+    }
+
+    fn diagonal(&self) -> DVector<T> {
+        DVector::from_fn(self.nrows(), |i| {
+            let mut res = T::zero();
+            for (j, v) in self.row_entries(i) {
+                if j == i {
+                    res = res + v;
+                }
+            }
+            res
+        })
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize> LinearOperator<T> for Matrix<T, M, N> {
+    fn nrows(&self) -> usize {
+        M
+    }
+
+    fn ncols(&self) -> usize {
+        N
+    }
+
+    fn apply(&self, x: &DVector<T>) -> DVector<T> {
+        if x.len() != N {
+            panic!(
+                "Matrix: cannot multiply {} columns by vector of length {}",
+                N,
+                x.len()
+            );
+        }
+        DVector::from_fn(M, |j| {
+            let mut sum = T::zero();
+            for (item1, item2) in self.data[j].iter().zip(x.as_slice()) {
+                sum = sum + *item1 * *item2;
+            }
+            sum
+        })
+    }
+}
+
+impl<T: Scalar, const M: usize, const N: usize> RowAccess<T> for Matrix<T, M, N> {
+    fn row_entries(&self, i: usize) -> Vec<(usize, T)> {
+        self.data[i].iter().copied().enumerate().collect()
+    }
+}
+
+impl<T: Scalar> LinearOperator<T> for DMatrix<T> {
+    fn nrows(&self) -> usize {
+        DMatrix::nrows(self)
+    }
+
+    fn ncols(&self) -> usize {
+        DMatrix::ncols(self)
+    }
+
+    fn apply(&self, x: &DVector<T>) -> DVector<T> {
+        self.mul_vec(x)
+    }
+}
+
+impl<T: Scalar> RowAccess<T> for DMatrix<T> {
+    fn row_entries(&self, i: usize) -> Vec<(usize, T)> {
+        self.data[i].iter().copied().enumerate().collect()
+    }
+}
+
+impl<T: Scalar> LinearOperator<T> for CsrMatrix<T> {
+    fn nrows(&self) -> usize {
+        CsrMatrix::nrows(self)
+    }
+
+    fn ncols(&self) -> usize {
+        CsrMatrix::ncols(self)
+    }
+
+    fn apply(&self, x: &DVector<T>) -> DVector<T> {
+        self.mul_vec(x)
+    }
+}
+
+impl<T: Scalar> RowAccess<T> for CsrMatrix<T> {
+    fn row_entries(&self, i: usize) -> Vec<(usize, T)> {
+        let (idx, values) = self.row(i);
+        idx.iter().copied().zip(values.iter().copied()).collect()
+    }
+}
+
+impl<T: Scalar> LinearOperator<T> for CscMatrix<T> {
+    fn nrows(&self) -> usize {
+        CscMatrix::nrows(self)
+    }
+
+    fn ncols(&self) -> usize {
+        CscMatrix::ncols(self)
+    }
+
+    fn apply(&self, x: &DVector<T>) -> DVector<T> {
+        self.mul_vec(x)
+    }
+}
+
+//rows are scattered over the columns, so a single row costs a search in every column,
+//all_row_entries and diagonal override the defaults with one pass over the columns
+impl<T: Scalar> RowAccess<T> for CscMatrix<T> {
+    fn row_entries(&self, i: usize) -> Vec<(usize, T)> {
+        let mut res = Vec::new();
+        for j in 0..CscMatrix::ncols(self) {
+            let (idx, values) = self.col(j);
+            if let Ok(k) = idx.binary_search(&i) {
+                res.push((j, values[k]));
+            }
+        }
+        res
+    }
+
+    fn all_row_entries(&self) -> Vec<Vec<(usize, T)>> {
+        let mut res = vec![Vec::new(); CscMatrix::nrows(self)];
+        for j in 0..CscMatrix::ncols(self) {
+            let (idx, values) = self.col(j);
+            for (&i, &v) in idx.iter().zip(values) {
+                res[i].push((j, v));
+            }
+        }
+        res
+    }
+
+    fn diagonal(&self) -> DVector<T> {
+        DVector::from_fn(CscMatrix::nrows(self), |i| {
+            if i < CscMatrix::ncols(self) {
+                self.get(i, i)
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod operators {
+    use super::*;
+    use crate::base_structs::sparse::CooMatrix;
+
+    #[test]
+    fn test_apply_matches_dense() {
+        let m = Matrix::from([[4., 0., 1.], [0., 0., 2.], [3., 5., 0.]]);
+        let x = DVector::from(vec![1., 2., 3.]);
+        let expected = DVector::from(vec![7., 6., 13.]);
+        assert_eq!(m.apply(&x), expected);
+        assert_eq!(DMatrix::from(m.clone()).apply(&x), expected);
+        assert_eq!(CsrMatrix::from(&m).apply(&x), expected);
+        assert_eq!(CscMatrix::from(&m).apply(&x), expected);
+    }
+
+    #[test]
+    fn test_diagonal() {
+        let mut coo = CooMatrix::new(3, 3);
+        coo.push(0, 0, 2.);
+        coo.push(1, 0, 1.);
+        coo.push(2, 2, 5.);
+        let csr = coo.to_csr();
+        assert_eq!(csr.diagonal(), DVector::from(vec![2., 0., 5.]));
+        let m = Matrix::from([[2., 1.], [1., 3.]]);
+        assert_eq!(m.diagonal(), DVector::from(vec![2., 3.]));
+    }
+
+    #[test]
+    fn test_csc_row_entries() {
+        let m = Matrix::from([[4., 0., 1.], [0., 0., 2.], [3., 5., 0.], [0., 7., 0.]]);
+        let csc = CscMatrix::from(&m);
+        let csr = CsrMatrix::from(&m);
+        for i in 0..4 {
+            assert_eq!(csc.row_entries(i), csr.row_entries(i));
+        }
+        assert_eq!(csc.all_row_entries(), csr.all_row_entries());
+        assert_eq!(csc.diagonal(), DVector::from(vec![4., 0., 0., 0.]));
+        assert_eq!(csc.diagonal(), csr.diagonal());
+    }
+}
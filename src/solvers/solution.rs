@@ -0,0 +1,85 @@
+use crate::base_structs::dvector::DVector;
+use crate::num_traits::scalar::Scalar;
+use crate::solvers::operator::LinearOperator;
+
+//stopping criteria shared by every iterative solver
+//iteration stops once ||b - Ax|| / ||b|| <= tolerance
+#[derive(PartialEq, Debug, Clone)]
+pub struct Settings<T> {
+    pub tolerance: T,
+    pub max_iterations: usize,
+    pub initial_guess: Option<DVector<T>>,
+}
+
+impl<T: Scalar> Settings<T> {
+    pub fn new(tolerance: T, max_iterations: usize) -> Settings<T> {
+        Settings {
+            tolerance,
+            max_iterations,
+            initial_guess: None,
+        }
+    }
+
+    pub fn with_initial_guess(mut self, x0: DVector<T>) -> Settings<T> {
+        self.initial_guess = Some(x0);
+        self
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Solution<T> {
+    pub x: DVector<T>,
+    pub iterations: usize,
+    //relative residual norm, starting with the one of the initial guess
+    pub residuals: Vec<T>,
+    pub converged: bool,
+}
+
+impl<T: Scalar> Solution<T> {
+    pub fn residual(&self) -> T {
+        self.residuals[self.residuals.len() - 1]
+    }
+}
+
+//checks the system is square and returns the starting point and ||b||
+pub(crate) fn prepare<T: Scalar, A: LinearOperator<T> + ?Sized>(
+    a: &A,
+    b: &DVector<T>,
+    settings: &Settings<T>,
+) -> Result<(DVector<T>, T), String> {
+    if a.nrows() != a.ncols() {
+        return Err(String::from("matrix is not square"));
+    }
+    if a.nrows() != b.len() {
+        return Err(format!(
+            "Expected right hand side of length {} but got {}",
+            a.nrows(),
+            b.len()
+        ));
+    }
+    let x = match &settings.initial_guess {
+        Some(x0) if x0.len() != b.len() => {
+            return Err(format!(
+                "Expected initial guess of length {} but got {}",
+                b.len(),
+                x0.len()
+            ))
+        }
+        Some(x0) => x0.clone(),
+        None => DVector::zeros(b.len()),
+    };
+    //a zero right hand side would divide by zero, measure the absolute residual instead
+    let mut b_norm = b.abs();
+    if b_norm == T::zero() {
+        b_norm = T::one();
+    }
+    Ok((x, b_norm))
+}
+
+pub(crate) fn residual<T: Scalar, A: LinearOperator<T> + ?Sized>(
+    a: &A,
+    b: &DVector<T>,
+    x: &DVector<T>,
+) -> DVector<T> {
+    b.clone() - a.apply(x)
+}
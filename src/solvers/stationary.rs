@@ -0,0 +1,177 @@
+use crate::base_structs::dvector::DVector;
+use crate::num_traits::scalar::Scalar;
+use crate::solvers::operator::RowAccess;
+use crate::solvers::solution::{prepare, residual, Settings, Solution};
+
+//splits each row into its diagonal entry and the sum of the others times x
+fn split_row<T: Scalar>(entries: &[(usize, T)], i: usize, x: &DVector<T>) -> (T, T) {
+    let mut diag = T::zero();
+    let mut off = T::zero();
+    for &(j, v) in entries.iter() {
+        if j == i {
+            diag = diag + v;
+        } else {
+            off = off + v * x[j];
+        }
+    }
+    (diag, off)
+}
+
+fn iterate<T, A, F>(
+    a: &A,
+    b: &DVector<T>,
+    settings: &Settings<T>,
+    mut sweep: F,
+) -> Result<Solution<T>, String>
+where
+    T: Scalar,
+    A: RowAccess<T> + ?Sized,
+    F: FnMut(&mut DVector<T>) -> Result<(), String>,
+{
+    let (mut x, b_norm) = prepare(a, b, settings)?;
+    let mut residuals = vec![residual(a, b, &x).abs() / b_norm];
+    let mut iterations = 0;
+    let mut converged = residuals[0] <= settings.tolerance;
+    while !converged && iterations < settings.max_iterations {
+        sweep(&mut x)?;
+        iterations += 1;
+        residuals.push(residual(a, b, &x).abs() / b_norm);
+        converged = residuals[iterations] <= settings.tolerance;
+    }
+    Ok(Solution {
+        x,
+        iterations,
+        residuals,
+        converged,
+    })
+}
+
+//every component is updated from the previous iterate
+//converges for strictly diagonally dominant matrices
+pub fn jacobi<T: Scalar, A: RowAccess<T> + ?Sized>(
+    a: &A,
+    b: &DVector<T>,
+    settings: &Settings<T>,
+) -> Result<Solution<T>, String> {
+    let rows = a.all_row_entries();
+    iterate(a, b, settings, |x| {
+        let prev = x.clone();
+        for (i, entries) in rows.iter().enumerate() {
+            let (diag, off) = split_row(entries, i, &prev);
+            if diag == T::zero() {
+                return Err(String::from("matrix has a zero on the diagonal"));
+            }
+            x[i] = (b[i] - off) / diag;
+        }
+        Ok(())
+    })
+}
+
+//successive over-relaxation, components are updated in place
+//omega = 1 is gauss-seidel, 0 < omega < 2 is required for convergence
+pub fn sor<T: Scalar, A: RowAccess<T> + ?Sized>(
+    a: &A,
+    b: &DVector<T>,
+    omega: T,
+    settings: &Settings<T>,
+) -> Result<Solution<T>, String> {
+    let rows = a.all_row_entries();
+    iterate(a, b, settings, |x| {
+        for (i, entries) in rows.iter().enumerate() {
+            let (diag, off) = split_row(entries, i, x);
+            if diag == T::zero() {
+                return Err(String::from("matrix has a zero on the diagonal"));
+            }
+            x[i] = (T::one() - omega) * x[i] + omega * (b[i] - off) / diag;
+        }
+        Ok(())
+    })
+}
+
+pub fn gauss_seidel<T: Scalar, A: RowAccess<T> + ?Sized>(
+    a: &A,
+    b: &DVector<T>,
+    settings: &Settings<T>,
+) -> Result<Solution<T>, String> {
+    sor(a, b, T::one(), settings)
+}
+
+#[cfg(test)]
+mod stationary_methods {
+    use super::*;
+    use crate::base_structs::matrix::Matrix;
+    use crate::base_structs::sparse::{CscMatrix, CsrMatrix};
+
+    fn system() -> (Matrix<f32, 3, 3>, DVector<f32>, DVector<f32>) {
+        let a = Matrix::from([[10., -1., 2.], [-1., 11., -1.], [2., -1., 10.]]);
+        let x = DVector::from(vec![1., 2., -1.]);
+        let b = DVector::from(vec![6., 22., -10.]);
+        (a, b, x)
+    }
+
+    fn assert_close(a: &DVector<f32>, b: &DVector<f32>) {
+        for i in 0..b.len() {
+            assert!((a[i] - b[i]).abs() < 1e-4, "{} vs {}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_jacobi() {
+        let (a, b, x) = system();
+        let sol = jacobi(&a, &b, &Settings::new(1e-6, 100)).unwrap();
+        assert!(sol.converged);
+        assert_close(&sol.x, &x);
+    }
+
+    #[test]
+    fn test_gauss_seidel_is_faster_than_jacobi() {
+        let (a, b, x) = system();
+        let a = CsrMatrix::from(&a);
+        let settings = Settings::new(1e-6, 100);
+        let j = jacobi(&a, &b, &settings).unwrap();
+        let gs = gauss_seidel(&a, &b, &settings).unwrap();
+        assert!(gs.converged);
+        assert!(gs.iterations < j.iterations);
+        assert_close(&gs.x, &x);
+    }
+
+    #[test]
+    fn test_csc_matches_csr() {
+        let (a, b, x) = system();
+        let settings = Settings::new(1e-6, 100);
+        let csr = gauss_seidel(&CsrMatrix::from(&a), &b, &settings).unwrap();
+        let csc = gauss_seidel(&CscMatrix::from(&a), &b, &settings).unwrap();
+        assert_eq!(csc, csr);
+        assert_close(&csc.x, &x);
+        let csc = jacobi(&CscMatrix::from(&a), &b, &settings).unwrap();
+        assert_eq!(csc, jacobi(&a, &b, &settings).unwrap());
+    }
+
+    #[test]
+    fn test_sor() {
+        let (a, b, x) = system();
+        let sol = sor(&a, &b, 1.1, &Settings::new(1e-6, 100)).unwrap();
+        assert!(sol.converged);
+        assert_close(&sol.x, &x);
+        for w in sol.residuals.windows(2) {
+            assert!(w[1] <= w[0]);
+        }
+    }
+
+    #[test]
+    fn test_not_converged() {
+        let (a, b, _) = system();
+        let sol = jacobi(&a, &b, &Settings::new(1e-6, 2)).unwrap();
+        assert!(!sol.converged);
+        assert_eq!(sol.iterations, 2);
+        assert_eq!(sol.residuals.len(), 3);
+    }
+
+    #[test]
+    fn test_zero_diagonal() {
+        let a = Matrix::from([[0., 1.], [1., 0.]]);
+        let b = DVector::from(vec![1., 1.]);
+        let res = gauss_seidel(&a, &b, &Settings::new(1e-6, 10));
+        assert_eq!(res, Err(String::from("matrix has a zero on the diagonal")));
+    }
+}
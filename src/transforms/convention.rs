@@ -0,0 +1,28 @@
+use crate::base_structs::matrix::TMatrix;
+use crate::num_traits::scalar::Scalar;
+
+//which way the z axis points relative to x and y
+//right handed (opengl) looks down -z, left handed (directx) looks down +z
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Handedness {
+    Left,
+    Right,
+}
+
+//column vectors are transformed as M * v and compose right to left,
+//row vectors as v * M and compose left to right; one is the transpose of the other
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Convention {
+    ColumnVector,
+    RowVector,
+}
+
+impl Convention {
+    //builders produce column vector matrices and pass them through here
+    pub(crate) fn apply<T: Scalar, const M: usize>(&self, mut m: TMatrix<T, M>) -> TMatrix<T, M> {
+        match self {
+            Convention::ColumnVector => m,
+            Convention::RowVector => m.transpose(),
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use crate::base_structs::matrix::{Matrix, TMatrix3};
+use crate::num_traits::real::Real;
+
+//the axes in the order the rotations are applied
+//the first six are tait-bryan (three different axes), the last six proper euler (first = last)
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+//intrinsic rotations are about the axes of the rotating body (x, then the new y, ...)
+//extrinsic rotations are about the fixed world axes
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Frame {
+    Intrinsic,
+    Extrinsic,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct EulerAngles<T> {
+    //radians, angles[n] is the rotation about the n-th axis of the order
+    pub angles: [T; 3],
+    pub order: EulerOrder,
+    pub frame: Frame,
+    //the middle angle aligned the first and last axes, only their sum is known
+    //the last rotation (first for extrinsic) is set to zero in that case
+    pub gimbal_lock: bool,
+}
+
+impl EulerOrder {
+    pub fn axes(&self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        }
+    }
+
+    pub fn is_proper(&self) -> bool {
+        let a = self.axes();
+        a[0] == a[2]
+    }
+
+    pub fn reversed(&self) -> EulerOrder {
+        match self {
+            EulerOrder::XYZ => EulerOrder::ZYX,
+            EulerOrder::XZY => EulerOrder::YZX,
+            EulerOrder::YXZ => EulerOrder::ZXY,
+            EulerOrder::YZX => EulerOrder::XZY,
+            EulerOrder::ZXY => EulerOrder::YXZ,
+            EulerOrder::ZYX => EulerOrder::XYZ,
+            proper => *proper,
+        }
+    }
+}
+
+//column vector rotation about one coordinate axis
+fn axis_rotation<T: Real>(axis: usize, angle: T) -> TMatrix3<T> {
+    let (c, s) = (angle.cos(), angle.sin());
+    let (j, k) = ((axis + 1) % 3, (axis + 2) % 3);
+    Matrix::from_fn(|r, col| {
+        if r == axis || col == axis {
+            if r == col {
+                T::one()
+            } else {
+                T::zero()
+            }
+        } else if r == col {
+            c
+        } else if r == k && col == j {
+            s
+        } else {
+            -s
+        }
+    })
+}
+
+impl<T: Real> EulerAngles<T> {
+    pub fn new(angles: [T; 3], order: EulerOrder, frame: Frame) -> EulerAngles<T> {
+        EulerAngles {
+            angles,
+            order,
+            frame,
+            gimbal_lock: false,
+        }
+    }
+
+    //column vector rotation matrix
+    pub fn to_matrix(&self) -> TMatrix3<T> {
+        let axes = self.order.axes();
+        let r: Vec<TMatrix3<T>> = (0..3)
+            .map(|n| axis_rotation(axes[n], self.angles[n]))
+            .collect();
+        match self.frame {
+            //each rotation is about an already rotated axis, so it applies on the right
+            Frame::Intrinsic => r[0].clone() * r[1].clone() * r[2].clone(),
+            Frame::Extrinsic => r[2].clone() * r[1].clone() * r[0].clone(),
+        }
+    }
+
+    //m has to be a proper rotation matrix for column vectors
+    //tait-bryan middle angles are in [-pi/2, pi/2], proper euler middle angles in [0, pi]
+    pub fn from_matrix(m: &TMatrix3<T>, order: EulerOrder, frame: Frame) -> EulerAngles<T> {
+        //extrinsic abc is the same rotation as intrinsic cba with the angles reversed
+        if frame == Frame::Extrinsic {
+            let e = EulerAngles::from_matrix(m, order.reversed(), Frame::Intrinsic);
+            return EulerAngles {
+                angles: [e.angles[2], e.angles[1], e.angles[0]],
+                order,
+                frame,
+                gimbal_lock: e.gimbal_lock,
+            };
+        }
+        let r = m.as_vec();
+        let [i, j, _] = order.axes();
+        let k = 3 - i - j;
+        //+1 when (i, j, k) is a cyclic permutation of (x, y, z)
+        let e = if (i + 1) % 3 == j {
+            T::one()
+        } else {
+            -T::one()
+        };
+        let tol = T::epsilon().sqrt();
+        let (first, middle, last, lock) = if order.is_proper() {
+            let sin = (r[i][j] * r[i][j] + r[i][k] * r[i][k]).sqrt();
+            let middle = sin.atan2(r[i][i]);
+            if sin > tol {
+                let first = r[j][i].atan2(-e * r[k][i]);
+                let last = r[i][j].atan2(e * r[i][k]);
+                (first, middle, last, false)
+            } else {
+                ((e * r[k][j]).atan2(r[j][j]), middle, T::zero(), true)
+            }
+        } else {
+            let cos = (r[i][i] * r[i][i] + r[i][j] * r[i][j]).sqrt();
+            let middle = (e * r[i][k]).atan2(cos);
+            if cos > tol {
+                let first = (-e * r[j][k]).atan2(r[k][k]);
+                let last = (-e * r[i][j]).atan2(r[i][i]);
+                (first, middle, last, false)
+            } else {
+                ((e * r[k][j]).atan2(r[j][j]), middle, T::zero(), true)
+            }
+        };
+        EulerAngles {
+            angles: [first, middle, last],
+            order,
+            frame,
+            gimbal_lock: lock,
+        }
+    }
+}
+
+impl<T: Real> TMatrix3<T> {
+    pub fn from_euler(angles: [T; 3], order: EulerOrder, frame: Frame) -> TMatrix3<T> {
+        EulerAngles::new(angles, order, frame).to_matrix()
+    }
+
+    pub fn to_euler(&self, order: EulerOrder, frame: Frame) -> EulerAngles<T> {
+        EulerAngles::from_matrix(self, order, frame)
+    }
+}
+
+#[cfg(test)]
+mod euler_angles {
+    use super::*;
+    use crate::base_structs::quaternion::Quaternion;
+    use crate::base_structs::vector::Vector;
+    use crate::utils::comp::matrices_are_equal;
+    use std::f32::consts::FRAC_PI_2;
+
+    const ORDERS: [EulerOrder; 12] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+        EulerOrder::XYX,
+        EulerOrder::XZX,
+        EulerOrder::YXY,
+        EulerOrder::YZY,
+        EulerOrder::ZXZ,
+        EulerOrder::ZYZ,
+    ];
+
+    fn close(a: &TMatrix3<f32>, b: &TMatrix3<f32>) -> bool {
+        let (a, b) = (a.as_vec(), b.as_vec());
+        (0..3).all(|j| (0..3).all(|i| (a[j][i] - b[j][i]).abs() < 1e-5))
+    }
+
+    #[test]
+    fn test_intrinsic_matches_quaternion_composition() {
+        let q = Quaternion::from_axis_angle(&Vector::from([0., 0., 1.]), 0.3)
+            * Quaternion::from_axis_angle(&Vector::from([0., 1., 0.]), -0.4)
+            * Quaternion::from_axis_angle(&Vector::from([1., 0., 0.]), 1.1);
+        let m = TMatrix3::from_euler([0.3, -0.4, 1.1], EulerOrder::ZYX, Frame::Intrinsic);
+        assert!(matrices_are_equal(&m, &q.to_matrix3()));
+        let m = TMatrix3::from_euler([1.1, -0.4, 0.3], EulerOrder::XYZ, Frame::Extrinsic);
+        assert!(matrices_are_equal(&m, &q.to_matrix3()));
+    }
+
+    #[test]
+    fn test_round_trip_all_orders() {
+        for order in ORDERS.iter() {
+            let middle = if order.is_proper() { 1.2 } else { -0.6 };
+            for frame in [Frame::Intrinsic, Frame::Extrinsic] {
+                let angles = [0.4, middle, -2.1];
+                let m = TMatrix3::from_euler(angles, *order, frame);
+                let e = m.to_euler(*order, frame);
+                assert!(!e.gimbal_lock);
+                for (got, want) in e.angles.iter().zip(angles.iter()) {
+                    assert!(
+                        (got - want).abs() < 1e-4,
+                        "{:?} {:?} {:?}",
+                        order,
+                        frame,
+                        e.angles
+                    );
+                }
+                assert!(close(&e.to_matrix(), &m));
+            }
+        }
+    }
+
+    #[test]
+    fn test_gimbal_lock() {
+        for order in ORDERS.iter() {
+            let middle = if order.is_proper() { 0. } else { FRAC_PI_2 };
+            for frame in [Frame::Intrinsic, Frame::Extrinsic] {
+                let m = TMatrix3::from_euler([0.5, middle, 0.25], *order, frame);
+                let e = m.to_euler(*order, frame);
+                assert!(e.gimbal_lock, "{:?} {:?}", order, frame);
+                //individual angles are lost but the rotation is preserved
+                assert!(close(&e.to_matrix(), &m), "{:?} {:?}", order, frame);
+            }
+        }
+    }
+}
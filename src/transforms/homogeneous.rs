@@ -0,0 +1,126 @@
+use crate::base_structs::matrix::{Matrix, TMatrix3, TMatrix4};
+use crate::base_structs::vector::{TVector2, TVector3, TVector4, Vector};
+use crate::num_traits::scalar::Scalar;
+
+//points get w = 1 so translations apply, directions get w = 0 so they don't
+impl<T: Scalar> TVector2<T> {
+    pub fn extend(&self, w: T) -> TVector3<T> {
+        Vector::from([self[0], self[1], w])
+    }
+}
+
+impl<T: Scalar> TVector3<T> {
+    pub fn extend(&self, w: T) -> TVector4<T> {
+        Vector::from([self[0], self[1], self[2], w])
+    }
+
+    //drops the last component
+    pub fn truncate(&self) -> TVector2<T> {
+        Vector::from([self[0], self[1]])
+    }
+}
+
+impl<T: Scalar> TVector4<T> {
+    pub fn truncate(&self) -> TVector3<T> {
+        Vector::from([self[0], self[1], self[2]])
+    }
+
+    //perspective divide, back from homogeneous to cartesian coordinates
+    pub fn project(&self) -> TVector3<T> {
+        self.truncate() / self[3]
+    }
+}
+
+//all of these treat the matrix as acting on column vectors (M * v)
+impl<T: Scalar> TMatrix4<T> {
+    pub fn transform_point(&self, p: &TVector3<T>) -> TVector3<T> {
+        self.clone().mul_vec(&p.extend(T::one())).project()
+    }
+
+    //directions ignore the translation part
+    pub fn transform_vector(&self, v: &TVector3<T>) -> TVector3<T> {
+        self.clone().mul_vec(&v.extend(T::zero())).truncate()
+    }
+
+    //upper left 3x3 block, the rotation, scale and shear part of an affine transform
+    pub fn linear_part(&self) -> TMatrix3<T> {
+        let d = self.as_vec();
+        Matrix::from_fn(|j, i| d[j][i])
+    }
+
+    //normals have to be transformed by the inverse transpose to stay perpendicular
+    //to surfaces under non-uniform scaling
+    pub fn normal_matrix(&self) -> Result<TMatrix3<T>, String> {
+        Ok(self.linear_part().inverse()?.transpose())
+    }
+}
+
+#[cfg(test)]
+mod homogeneous_coordinates {
+    use super::*;
+    use crate::transforms::convention::Convention;
+    use crate::utils::comp::{matrices_are_equal, vectors_are_equal};
+
+    #[test]
+    fn test_extend_and_truncate() {
+        let v = Vector::from([1., 2.]);
+        assert_eq!(v.extend(1.), Vector::from([1., 2., 1.]));
+        assert_eq!(v.extend(1.).truncate(), v);
+        let v = Vector::from([1., 2., 3.]);
+        assert_eq!(v.extend(0.), Vector::from([1., 2., 3., 0.]));
+        assert_eq!(v.extend(0.).truncate(), v);
+        assert_eq!(
+            Vector::from([2., 4., 6., 2.]).project(),
+            Vector::from([1., 2., 3.])
+        );
+    }
+
+    #[test]
+    fn test_transform_point_and_vector() {
+        let t = TMatrix4::translation(&Vector::from([1., 2., 3.]), Convention::ColumnVector);
+        let p = Vector::from([1., 1., 1.]);
+        assert_eq!(t.transform_point(&p), Vector::from([2., 3., 4.]));
+        assert_eq!(t.transform_vector(&p), p);
+    }
+
+    #[test]
+    fn test_transform_point_perspective_divide() {
+        //copies z into w, so x and y are divided by the depth
+        let m = Matrix::from([
+            [1., 0., 0., 0.],
+            [0., 1., 0., 0.],
+            [0., 0., 1., 0.],
+            [0., 0., 1., 0.],
+        ]);
+        let p = m.transform_point(&Vector::from([4., 2., 2.]));
+        assert_eq!(p, Vector::from([2., 1., 1.]));
+    }
+
+    #[test]
+    fn test_normal_matrix() {
+        let s = TMatrix4::scaling(&Vector::from([2., 1., 1.]));
+        let n = s.normal_matrix().unwrap();
+        assert!(matrices_are_equal(
+            &n,
+            &Matrix::from([[0.5, 0., 0.], [0., 1., 0.], [0., 0., 1.]])
+        ));
+        //the normal of the plane x + y = 0 stays perpendicular to it after scaling
+        let normal = n.clone().mul_vec(&Vector::from([1., 1., 0.]));
+        let tangent = s.transform_vector(&Vector::from([1., -1., 0.]));
+        assert_eq!(normal.dot(&tangent), 0.);
+        let r = TMatrix4::rotation_z(0.8, Convention::ColumnVector);
+        assert!(matrices_are_equal(
+            &r.normal_matrix().unwrap(),
+            &r.linear_part()
+        ));
+        assert!(vectors_are_equal(
+            &r.transform_vector(&Vector::from([0., 0., 1.])),
+            &Vector::from([0., 0., 1.])
+        ));
+        let flat = TMatrix4::scaling(&Vector::from([1., 0., 1.]));
+        assert_eq!(
+            flat.normal_matrix(),
+            Err(String::from("matrix is singular"))
+        );
+    }
+}
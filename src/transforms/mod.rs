@@ -0,0 +1,5 @@
+pub mod convention;
+pub mod euler;
+pub mod homogeneous;
+pub mod transform2d;
+pub mod transform3d;
@@ -0,0 +1,199 @@
+use crate::base_structs::matrix::{Matrix, TMatrix3};
+use crate::base_structs::vector::{TVector2, Vector};
+use crate::num_traits::real::Real;
+use crate::num_traits::scalar::Scalar;
+use crate::transforms::convention::Convention;
+
+//the pieces of an affine matrix, recombined as translation * rotation * scale * shear
+//a negative y scale means the transform contains a reflection
+#[derive(PartialEq, Debug, Clone)]
+pub struct Affine2Parts<T> {
+    pub translation: TVector2<T>,
+    pub rotation: T,
+    pub scale: TVector2<T>,
+    //x shear factor, x' = x + shear * y
+    pub shear: T,
+}
+
+impl<T: Scalar> TMatrix3<T> {
+    pub fn scaling(v: &TVector2<T>) -> TMatrix3<T> {
+        let (o, z) = (T::one(), T::zero());
+        Matrix::from([[v[0], z, z], [z, v[1], z], [z, z, o]])
+    }
+
+    pub fn translation(v: &TVector2<T>, convention: Convention) -> TMatrix3<T> {
+        let (o, z) = (T::one(), T::zero());
+        convention.apply(Matrix::from([[o, z, v[0]], [z, o, v[1]], [z, z, o]]))
+    }
+
+    //x' = x + shx * y, y' = y + shy * x
+    pub fn shearing(shx: T, shy: T, convention: Convention) -> TMatrix3<T> {
+        let (o, z) = (T::one(), T::zero());
+        convention.apply(Matrix::from([[o, shx, z], [shy, o, z], [z, z, o]]))
+    }
+
+    //mirror across the line going through point with the given direction
+    pub fn reflection(
+        point: &TVector2<T>,
+        direction: &TVector2<T>,
+        convention: Convention,
+    ) -> TMatrix3<T> {
+        let d = direction.normalize();
+        let (o, z) = (T::one(), T::zero());
+        let two = o + o;
+        //2 d d^T - I reflects across the line through the origin
+        let a = two * d[0] * d[0] - o;
+        let b = two * d[0] * d[1];
+        let c = two * d[1] * d[1] - o;
+        //move the line to the origin, reflect, move back
+        let (px, py) = (point[0], point[1]);
+        let tx = px - (a * px + b * py);
+        let ty = py - (b * px + c * py);
+        convention.apply(Matrix::from([[a, b, tx], [b, c, ty], [z, z, o]]))
+    }
+
+    //matrix acting on column vectors (M * v), with perspective divide
+    pub fn transform_point(&self, p: &TVector2<T>) -> TVector2<T> {
+        let v = self.clone().mul_vec(&p.extend(T::one()));
+        Vector::from([v[0] / v[2], v[1] / v[2]])
+    }
+
+    pub fn transform_vector(&self, v: &TVector2<T>) -> TVector2<T> {
+        self.clone().mul_vec(&v.extend(T::zero())).truncate()
+    }
+}
+
+impl<T: Real> TMatrix3<T> {
+    //counter-clockwise, in radians
+    pub fn rotation(angle: T, convention: Convention) -> TMatrix3<T> {
+        let (o, z, c, s) = (T::one(), T::zero(), angle.cos(), angle.sin());
+        convention.apply(Matrix::from([[c, -s, z], [s, c, z], [z, z, o]]))
+    }
+
+    //qr decomposition of the linear part: the first column gives the rotation and x scale,
+    //what is left is upper triangular and holds the y scale and the shear
+    pub fn decompose_affine(&self, convention: Convention) -> Result<Affine2Parts<T>, String> {
+        let m = convention.apply(self.clone()).as_vec();
+        if m[2][0] != T::zero() || m[2][1] != T::zero() || m[2][2] != T::one() {
+            return Err(String::from("matrix is not affine"));
+        }
+        let (a, b, c, d) = (m[0][0], m[0][1], m[1][0], m[1][1]);
+        let sx = (a * a + c * c).sqrt();
+        if sx == T::zero() {
+            return Err(String::from("matrix is singular"));
+        }
+        let sy = (a * d - b * c) / sx;
+        if sy == T::zero() {
+            return Err(String::from("matrix is singular"));
+        }
+        Ok(Affine2Parts {
+            translation: Vector::from([m[0][2], m[1][2]]),
+            rotation: c.atan2(a),
+            scale: Vector::from([sx, sy]),
+            shear: (a * b + c * d) / (sx * sx),
+        })
+    }
+}
+
+impl<T: Real> Affine2Parts<T> {
+    pub fn compose(&self, convention: Convention) -> TMatrix3<T> {
+        let c = Convention::ColumnVector;
+        let m = TMatrix3::translation(&self.translation, c)
+            * TMatrix3::rotation(self.rotation, c)
+            * TMatrix3::scaling(&self.scale)
+            * TMatrix3::shearing(self.shear, T::zero(), c);
+        convention.apply(m)
+    }
+}
+
+#[cfg(test)]
+mod affine_2d {
+    use super::*;
+    use crate::utils::comp::{floats_are_equal, matrices_are_equal, vectors_are_equal};
+    use std::f32::consts::FRAC_PI_2;
+
+    const C: Convention = Convention::ColumnVector;
+
+    #[test]
+    fn test_builders() {
+        let p = Vector::from([1., 2.]);
+        let t = TMatrix3::translation(&Vector::from([3., -1.]), C);
+        assert_eq!(t.transform_point(&p), Vector::from([4., 1.]));
+        assert_eq!(t.transform_vector(&p), p);
+        let s = TMatrix3::scaling(&Vector::from([2., 3.]));
+        assert_eq!(s.transform_point(&p), Vector::from([2., 6.]));
+        let r = TMatrix3::rotation(FRAC_PI_2, C);
+        assert!(vectors_are_equal(
+            &r.transform_point(&p),
+            &Vector::from([-2., 1.])
+        ));
+        let h = TMatrix3::shearing(1., 0., C);
+        assert_eq!(h.transform_point(&p), Vector::from([3., 2.]));
+    }
+
+    #[test]
+    fn test_reflection() {
+        //across the horizontal line y = 1
+        let m = TMatrix3::reflection(&Vector::from([0., 1.]), &Vector::from([1., 0.]), C);
+        assert_eq!(
+            m.transform_point(&Vector::from([5., 3.])),
+            Vector::from([5., -1.])
+        );
+        //across the diagonal y = x, which swaps the coordinates
+        let m = TMatrix3::reflection(&Vector::from([2., 2.]), &Vector::from([3., 3.]), C);
+        assert!(vectors_are_equal(
+            &m.transform_point(&Vector::from([1., 4.])),
+            &Vector::from([4., 1.])
+        ));
+        assert!(matrices_are_equal(&(m.clone() * m), &TMatrix3::identity()));
+    }
+
+    #[test]
+    fn test_composition_order() {
+        //column vectors: the right-most matrix is applied first
+        let t = TMatrix3::translation(&Vector::from([1., 0.]), C);
+        let s = TMatrix3::scaling(&Vector::from([2., 2.]));
+        let p = Vector::from([1., 1.]);
+        assert_eq!(
+            (t.clone() * s.clone()).transform_point(&p),
+            Vector::from([3., 2.])
+        );
+        assert_eq!((s * t).transform_point(&p), Vector::from([4., 2.]));
+    }
+
+    #[test]
+    fn test_decompose_round_trip() {
+        let parts = Affine2Parts {
+            translation: Vector::from([4., -2.]),
+            rotation: 0.6,
+            scale: Vector::from([2., -0.5]),
+            shear: 0.3,
+        };
+        let m = parts.compose(C);
+        let back = m.decompose_affine(C).unwrap();
+        assert!(vectors_are_equal(&back.translation, &parts.translation));
+        assert!(floats_are_equal(back.rotation, parts.rotation));
+        assert!(vectors_are_equal(&back.scale, &parts.scale));
+        assert!(floats_are_equal(back.shear, parts.shear));
+        let row = parts.compose(Convention::RowVector);
+        assert!(matrices_are_equal(
+            &back.compose(Convention::RowVector),
+            &row
+        ));
+        assert_eq!(row.decompose_affine(Convention::RowVector), Ok(back));
+    }
+
+    #[test]
+    fn test_decompose_errors() {
+        let m = Matrix::from([[1., 0., 0.], [0., 1., 0.], [1., 0., 1.]]);
+        assert_eq!(
+            m.decompose_affine(C),
+            Err(String::from("matrix is not affine"))
+        );
+        let m = TMatrix3::scaling(&Vector::from([1., 0.]));
+        assert_eq!(
+            m.decompose_affine(C),
+            Err(String::from("matrix is singular"))
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use crate::base_structs::matrix::{Matrix, TMatrix4};
+use crate::base_structs::vector::TVector3;
+use crate::exercises::ex06::cross_product;
+use crate::num_traits::real::Real;
+use crate::transforms::convention::{Convention, Handedness};
+
+impl<T: Real> TMatrix4<T> {
+    //moves points by v, directions are not affected
+    pub fn translation(v: &TVector3<T>, convention: Convention) -> TMatrix4<T> {
+        let (o, z) = (T::one(), T::zero());
+        let m = Matrix::from([
+            [o, z, z, v[0]],
+            [z, o, z, v[1]],
+            [z, z, o, v[2]],
+            [z, z, z, o],
+        ]);
+        convention.apply(m)
+    }
+
+    //diagonal, so the same for both conventions
+    pub fn scaling(v: &TVector3<T>) -> TMatrix4<T> {
+        let (o, z) = (T::one(), T::zero());
+        Matrix::from([
+            [v[0], z, z, z],
+            [z, v[1], z, z],
+            [z, z, v[2], z],
+            [z, z, z, o],
+        ])
+    }
+
+    //angles are in radians, counter-clockwise when looking from the positive axis toward the origin
+    pub fn rotation_x(angle: T, convention: Convention) -> TMatrix4<T> {
+        let (o, z, c, s) = (T::one(), T::zero(), angle.cos(), angle.sin());
+        let m = Matrix::from([[o, z, z, z], [z, c, -s, z], [z, s, c, z], [z, z, z, o]]);
+        convention.apply(m)
+    }
+
+    pub fn rotation_y(angle: T, convention: Convention) -> TMatrix4<T> {
+        let (o, z, c, s) = (T::one(), T::zero(), angle.cos(), angle.sin());
+        let m = Matrix::from([[c, z, s, z], [z, o, z, z], [-s, z, c, z], [z, z, z, o]]);
+        convention.apply(m)
+    }
+
+    pub fn rotation_z(angle: T, convention: Convention) -> TMatrix4<T> {
+        let (o, z, c, s) = (T::one(), T::zero(), angle.cos(), angle.sin());
+        let m = Matrix::from([[c, -s, z, z], [s, c, z, z], [z, z, o, z], [z, z, z, o]]);
+        convention.apply(m)
+    }
+
+    //rodrigues formula: R = cos I + sin [k]x + (1 - cos) k k^T, k is normalized first
+    pub fn rotation_axis_angle(
+        axis: &TVector3<T>,
+        angle: T,
+        convention: Convention,
+    ) -> TMatrix4<T> {
+        let k = axis.normalize();
+        let (x, y, z) = (k[0], k[1], k[2]);
+        let (o, n, c, s) = (T::one(), T::zero(), angle.cos(), angle.sin());
+        let t = o - c;
+        let m = Matrix::from([
+            [t * x * x + c, t * x * y - s * z, t * x * z + s * y, n],
+            [t * x * y + s * z, t * y * y + c, t * y * z - s * x, n],
+            [t * x * z - s * y, t * y * z + s * x, t * z * z + c, n],
+            [n, n, n, o],
+        ]);
+        convention.apply(m)
+    }
+
+    //view matrix placing the camera at eye and pointing it at target
+    pub fn look_at(
+        eye: &TVector3<T>,
+        target: &TVector3<T>,
+        up: &TVector3<T>,
+        handedness: Handedness,
+        convention: Convention,
+    ) -> TMatrix4<T> {
+        let f = (target.clone() - eye.clone()).normalize();
+        let (s, u, back) = match handedness {
+            Handedness::Right => {
+                let s = cross_product(&f, up).normalize();
+                let u = cross_product(&s, &f);
+                (s, u, -T::one())
+            }
+            Handedness::Left => {
+                let s = cross_product(up, &f).normalize();
+                let u = cross_product(&f, &s);
+                (s, u, T::one())
+            }
+        };
+        let z = f.clone() * back;
+        let (o, n) = (T::one(), T::zero());
+        let m = Matrix::from([
+            [s[0], s[1], s[2], -s.dot(eye)],
+            [u[0], u[1], u[2], -u.dot(eye)],
+            [z[0], z[1], z[2], -z.dot(eye)],
+            [n, n, n, o],
+        ]);
+        convention.apply(m)
+    }
+}
+
+#[cfg(test)]
+mod builders_3d {
+    use super::*;
+    use crate::base_structs::vector::Vector;
+    use crate::utils::comp::{matrices_are_equal, vectors_are_equal};
+    use std::f32::consts::FRAC_PI_2;
+
+    fn apply(m: &TMatrix4<f32>, p: [f32; 3]) -> Vector<f32, 3> {
+        let mut m = m.clone();
+        let v = m.mul_vec(&Vector::from([p[0], p[1], p[2], 1.]));
+        Vector::from([v[0], v[1], v[2]])
+    }
+
+    #[test]
+    fn test_translation_and_scaling() {
+        let t = TMatrix4::translation(&Vector::from([1., 2., 3.]), Convention::ColumnVector);
+        assert_eq!(apply(&t, [1., 1., 1.]), Vector::from([2., 3., 4.]));
+        let s = TMatrix4::scaling(&Vector::from([2., 3., 4.]));
+        assert_eq!(apply(&s, [1., 1., 1.]), Vector::from([2., 3., 4.]));
+    }
+
+    #[test]
+    fn test_row_vector_is_transpose() {
+        let v = Vector::from([1., 2., 3.]);
+        let mut col = TMatrix4::translation(&v, Convention::ColumnVector);
+        let row = TMatrix4::translation(&v, Convention::RowVector);
+        assert_eq!(col.transpose(), row);
+        assert_eq!(row.as_vec()[3], vec![1., 2., 3., 1.]);
+    }
+
+    #[test]
+    fn test_axis_rotations() {
+        let c = Convention::ColumnVector;
+        let x = apply(&TMatrix4::rotation_x(FRAC_PI_2, c), [0., 1., 0.]);
+        assert!(vectors_are_equal(&x, &Vector::from([0., 0., 1.])));
+        let y = apply(&TMatrix4::rotation_y(FRAC_PI_2, c), [0., 0., 1.]);
+        assert!(vectors_are_equal(&y, &Vector::from([1., 0., 0.])));
+        let z = apply(&TMatrix4::rotation_z(FRAC_PI_2, c), [1., 0., 0.]);
+        assert!(vectors_are_equal(&z, &Vector::from([0., 1., 0.])));
+    }
+
+    #[test]
+    fn test_axis_angle_matches_axis_rotations() {
+        let c = Convention::ColumnVector;
+        let a = 0.7;
+        let pairs = [
+            ([3., 0., 0.], TMatrix4::rotation_x(a, c)),
+            ([0., 0.5, 0.], TMatrix4::rotation_y(a, c)),
+            ([0., 0., 2.], TMatrix4::rotation_z(a, c)),
+        ];
+        for (axis, expected) in pairs.iter() {
+            let r = TMatrix4::rotation_axis_angle(&Vector::from(*axis), a, c);
+            assert!(matrices_are_equal(&r, expected));
+        }
+        //a third of a turn around the diagonal cycles the axes
+        let r = TMatrix4::rotation_axis_angle(&Vector::from([1., 1., 1.]), FRAC_PI_2 * 4. / 3., c);
+        assert!(vectors_are_equal(
+            &apply(&r, [1., 0., 0.]),
+            &Vector::from([0., 1., 0.])
+        ));
+    }
+
+    #[test]
+    fn test_look_at_right_handed() {
+        let eye = Vector::from([0., 0., 5.]);
+        let target = Vector::from([0., 0., 0.]);
+        let up = Vector::from([0., 1., 0.]);
+        let m = TMatrix4::look_at(
+            &eye,
+            &target,
+            &up,
+            Handedness::Right,
+            Convention::ColumnVector,
+        );
+        //the target ends up straight ahead on -z
+        assert!(vectors_are_equal(
+            &apply(&m, [0., 0., 0.]),
+            &Vector::from([0., 0., -5.])
+        ));
+        assert!(vectors_are_equal(
+            &apply(&m, [1., 0., 5.]),
+            &Vector::from([1., 0., 0.])
+        ));
+    }
+
+    #[test]
+    fn test_look_at_left_handed() {
+        let eye = Vector::from([0., 0., -5.]);
+        let target = Vector::from([0., 0., 0.]);
+        let up = Vector::from([0., 1., 0.]);
+        let m = TMatrix4::look_at(
+            &eye,
+            &target,
+            &up,
+            Handedness::Left,
+            Convention::ColumnVector,
+        );
+        //the target ends up straight ahead on +z
+        assert!(vectors_are_equal(
+            &apply(&m, [0., 0., 0.]),
+            &Vector::from([0., 0., 5.])
+        ));
+        assert!(vectors_are_equal(
+            &apply(&m, [1., 2., -5.]),
+            &Vector::from([1., 2., 0.])
+        ));
+        let mut row =
+            TMatrix4::look_at(&eye, &target, &up, Handedness::Left, Convention::RowVector);
+        assert_eq!(row.transpose(), m);
+    }
+}
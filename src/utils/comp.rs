@@ -1,3 +1,5 @@
+use crate::base_structs::dmatrix::DMatrix;
+use crate::base_structs::dvector::DVector;
 use crate::base_structs::matrix::Matrix;
 use crate::base_structs::vector::Vector;
 
@@ -29,3 +31,25 @@ pub fn vectors_are_equal<const N: usize>(a: &Vector<f32, N>, b: &Vector<f32, N>)
     }
     true
 }
+
+pub fn dmatrices_are_equal(a: &DMatrix<f32>, b: &DMatrix<f32>) -> bool {
+    if a.shape() != b.shape() {
+        return false;
+    }
+    for j in 0..a.nrows() {
+        for i in 0..a.ncols() {
+            if !floats_are_equal(a[(j, i)], b[(j, i)]) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+pub fn dvectors_are_equal(a: &DVector<f32>, b: &DVector<f32>) -> bool {
+    a.len() == b.len()
+        && a.as_slice()
+            .iter()
+            .zip(b.as_slice())
+            .all(|(x, y)| floats_are_equal(*x, *y))
+}